@@ -2,9 +2,9 @@ use std::fmt::Display;
 
 use fake::faker::name::en::Name;
 use fake::{Dummy, Fake, Faker};
-use iced::widget::{button, container, row, text, Column};
+use iced::widget::{button, container, row, text};
 use iced::{Element, Length, Sandbox, Settings};
-use iced_flatlist::{get_start_end_pos, NewScrollable};
+use iced_flatlist::FlatList;
 
 pub fn main() {
     Example::run(Settings::default()).unwrap_or_else(|err| {
@@ -14,14 +14,12 @@ pub fn main() {
 
 struct Example {
     users: Vec<User>,
-    p_scroll_offset: f32,
     item_height: f32,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     DetailsPress(User),
-    SetScrollOffset(f32),
 }
 
 #[derive(Debug, Dummy, Clone)]
@@ -82,11 +80,7 @@ impl Sandbox for Example {
         for n in 0..100_000 {
             users.push(User::new(n, item_height));
         }
-        Example {
-            users,
-            item_height,
-            p_scroll_offset: 0.0,
-        }
+        Example { users, item_height }
     }
 
     fn title(&self) -> String {
@@ -98,43 +92,18 @@ impl Sandbox for Example {
             Message::DetailsPress(user) => {
                 println!("{:?}", user);
             }
-            Message::SetScrollOffset(offset) => self.p_scroll_offset = offset,
         }
     }
 
     fn view(&self) -> Element<Message> {
-        let view_height = 400.0;
-        let items_len = self.users.len();
-        let total_height = items_len as f32 * self.item_height;
-        let (start_index, end_index) = get_start_end_pos(
-            items_len,
-            self.p_scroll_offset,
-            self.item_height,
-            view_height,
-        );
-        let visible_items: Element<_> = self
-            .users
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, item)| {
-                if idx >= start_index && idx < end_index {
-                    Some(item)
-                } else {
-                    None
-                }
-            })
-            .fold(
-                Column::new().height(Length::Fixed(total_height)),
-                |col, user| col.push(user.view()),
-            )
-            .into();
-
-        let scrollable =
-            NewScrollable::new(visible_items).on_scroll(|r_off| Message::SetScrollOffset(r_off.y));
+        let list = FlatList::new(self.users.len(), self.item_height, |index| {
+            self.users[index].view()
+        })
+        .header(User::header());
 
-        container(scrollable)
+        container(list)
             .width(Length::Fill)
-            .height(Length::Fixed(view_height))
+            .height(Length::Fixed(400.0))
             .center_x()
             .center_y()
             .into()
@@ -0,0 +1,246 @@
+//! Feed a virtualized list from data that is loaded on demand.
+use std::ops::Range;
+
+use iced_native::Command;
+
+/// The items of a virtualized list, loaded on demand.
+///
+/// A list backed by a [`DataSource`] shows placeholder rows for the items
+/// that are not loaded yet and asks for them to be loaded, so the
+/// application can call [`DataSource::request`] and return its [`Command`].
+pub trait DataSource<Message> {
+    /// The type of the items.
+    type Item;
+
+    /// Returns the amount of items, loaded or not.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the item at `index`, if it is loaded.
+    fn get(&self, index: usize) -> Option<Self::Item>;
+
+    /// Returns `true` if the item at `index` is loaded.
+    fn is_loaded(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Produces a [`Command`] that loads the items in `range`.
+    fn request(&mut self, range: Range<usize>) -> Command<Message>;
+}
+
+/// A page of items loaded by a [`MemorySource`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// The index of the first item of the [`Page`].
+    pub start: usize,
+    /// The items of the [`Page`].
+    pub items: Vec<T>,
+}
+
+/// A [`DataSource`] that keeps every item in memory, but only hands out the
+/// pages that were requested and then loaded.
+///
+/// It is useful to try out lists backed by a [`DataSource`] without a real
+/// backend.
+#[allow(missing_debug_implementations)]
+pub struct MemorySource<T, Message> {
+    items: Vec<T>,
+    page_size: usize,
+    loaded: Vec<bool>,
+    pending: Vec<bool>,
+    on_load: fn(Page<T>) -> Message,
+}
+
+impl<T, Message> MemorySource<T, Message> {
+    /// Creates a new [`MemorySource`] of `items`, loaded in pages of
+    /// `page_size` items.
+    ///
+    /// Requested pages are delivered through the message produced by
+    /// `on_load`, and should be passed back to [`MemorySource::load`].
+    pub fn new(items: Vec<T>, page_size: usize, on_load: fn(Page<T>) -> Message) -> Self {
+        let page_size = page_size.max(1);
+        let pages = items.len().div_ceil(page_size);
+
+        Self {
+            items,
+            page_size,
+            loaded: vec![false; pages],
+            pending: vec![false; pages],
+            on_load,
+        }
+    }
+
+    /// Marks the items of the [`Page`] as loaded.
+    pub fn load(&mut self, page: Page<T>) {
+        let index = page.start / self.page_size;
+
+        if let Some(loaded) = self.loaded.get_mut(index) {
+            *loaded = true;
+            self.pending[index] = false;
+        }
+    }
+
+    /// Returns `true` if the item at `index` exists and the page holding it
+    /// is loaded.
+    fn is_page_loaded(&self, index: usize) -> bool {
+        index < self.items.len() && self.loaded[index / self.page_size]
+    }
+}
+
+impl<T, Message> DataSource<Message> for MemorySource<T, Message>
+where
+    T: Clone + Send + 'static,
+    Message: 'static,
+{
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        if self.is_page_loaded(index) {
+            self.items.get(index).cloned()
+        } else {
+            None
+        }
+    }
+
+    fn is_loaded(&self, index: usize) -> bool {
+        self.is_page_loaded(index)
+    }
+
+    fn request(&mut self, range: Range<usize>) -> Command<Message> {
+        let first = range.start / self.page_size;
+        let last = range.end.min(self.items.len()).div_ceil(self.page_size);
+
+        Command::batch((first..last).filter_map(|page| {
+            if self.loaded[page] || self.pending[page] {
+                return None;
+            }
+
+            self.pending[page] = true;
+
+            let start = page * self.page_size;
+            let end = (start + self.page_size).min(self.items.len());
+            let page = Page {
+                start,
+                items: self.items[start..end].to_vec(),
+            };
+
+            Some(Command::perform(async move { page }, self.on_load))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataSource, MemorySource, Page};
+
+    use iced_native::command::Action;
+    use iced_native::futures::executor::block_on;
+    use iced_native::Command;
+
+    fn source(len: u32) -> MemorySource<u32, Page<u32>> {
+        MemorySource::new((0..len).collect(), 10, |page| page)
+    }
+
+    /// Runs the [`Command`] returned by a request and returns the pages it
+    /// loads.
+    fn pages(command: Command<Page<u32>>) -> Vec<Page<u32>> {
+        command
+            .actions()
+            .into_iter()
+            .map(|action| match action {
+                Action::Future(future) => block_on(future),
+                _ => panic!("a request only loads pages"),
+            })
+            .collect()
+    }
+
+    fn starts(pages: &[Page<u32>]) -> Vec<usize> {
+        pages.iter().map(|page| page.start).collect()
+    }
+
+    #[test]
+    fn request_splits_the_range_in_pages() {
+        let mut source = source(25);
+        let pages = pages(source.request(5..22));
+
+        assert_eq!(starts(&pages), [0, 10, 20]);
+        assert_eq!(pages[0].items, (0..10).collect::<Vec<_>>());
+        assert_eq!(pages[2].items, [20, 21, 22, 23, 24]);
+    }
+
+    #[test]
+    fn request_skips_pending_and_loaded_pages() {
+        let mut source = source(40);
+        let mut requested = pages(source.request(0..20));
+
+        assert!(pages(source.request(0..20)).is_empty());
+        assert_eq!(starts(&pages(source.request(15..35))), [20, 30]);
+
+        source.load(requested.remove(0));
+
+        assert!(pages(source.request(0..10)).is_empty());
+        assert!(pages(source.request(0..40)).is_empty());
+    }
+
+    #[test]
+    fn request_stops_at_the_end() {
+        let mut source = source(25);
+
+        assert_eq!(starts(&pages(source.request(20..100))), [20]);
+        assert!(pages(source.request(30..40)).is_empty());
+        assert!(pages(source.request(100..200)).is_empty());
+    }
+
+    #[test]
+    fn request_nothing_from_an_empty_source() {
+        let mut source = source(0);
+
+        assert!(source.is_empty());
+        assert!(pages(source.request(0..10)).is_empty());
+    }
+
+    #[test]
+    fn load_hands_out_the_page() {
+        let mut source = source(25);
+        let mut pages = pages(source.request(0..25));
+
+        assert_eq!(source.len(), 25);
+        assert!(!source.is_loaded(12));
+        assert_eq!(source.get(12), None);
+
+        source.load(pages.remove(1));
+
+        assert!(source.is_loaded(12));
+        assert_eq!(source.get(12), Some(12));
+        assert!(!source.is_loaded(9));
+        assert_eq!(source.get(9), None);
+        assert!(!source.is_loaded(20));
+
+        source.load(pages.remove(1));
+
+        assert_eq!(source.get(24), Some(24));
+        assert!(!source.is_loaded(25));
+        assert_eq!(source.get(25), None);
+    }
+
+    #[test]
+    fn load_ignores_pages_past_the_end() {
+        let mut source = source(5);
+
+        source.load(Page {
+            start: 100,
+            items: vec![100],
+        });
+
+        assert!(!source.is_loaded(100));
+        assert_eq!(source.get(100), None);
+    }
+}
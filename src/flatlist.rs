@@ -1,7 +1,40 @@
-use iced::{Element, Size};
+use std::any::Any;
+use std::cell::{Cell, RefCell, RefMut};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher as _};
+use std::ops::Range;
 
+use iced_native::event::{self, Event};
+use iced_native::keyboard::{self, KeyCode};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::widget::operation::Focusable;
+use iced_native::widget::tree::{self, Tree};
+use iced_native::widget::{self, Operation, Space};
+use iced_native::window;
+use iced_native::{
+    Clipboard, Color, Command, Element, Hasher, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::data_source::DataSource;
+use crate::kinetic::Kinetic;
+use crate::new_scrollable::{
+    self, AutoScroll, Id, Properties, Smooth, StyleSheet, Viewport, Wheel,
+};
+use crate::row_heights::RowHeights;
 use crate::scroller::Scroller;
+use crate::sections::{Position, Sections};
+use crate::selection;
+use crate::skeleton::{self, Skeleton};
 
+/// Creates a [`Scroller`] showing the `items` that fit in `size`, in rows of
+/// `row_h` pixels.
+///
+/// Every line of the mouse wheel scrolls by a row, which can be changed with
+/// [`Scroller::wheel`], in pixels of the list.
 pub fn flatlist<'a, Message, Renderer, T: Sized, I>(
     size: Size,
     row_h: f32,
@@ -12,15 +45,15 @@ where
     Renderer: iced_native::Renderer,
     I: IntoIterator<Item = T> + Copy + 'a,
 {
-    let rows_fit = (size.height / row_h).floor() / 3.0;
     let item_count = items.into_iter().count();
-    let scroll_by = rows_fit / item_count as f32 * size.height;
 
-    Scroller::new(size, scroll_by, move |slider_pos| {
+    Scroller::new(size, row_h, move |slider_pos| {
         let slider_pos_pct = slider_pos / size.height;
         let filtered_i = get_subset_by_slider_position(items, slider_pos_pct, row_h, size.height);
         f(filtered_i)
     })
+    .content_height(row_h * item_count as f32)
+    .wheel(Wheel::new().rows(1, row_h))
 }
 
 pub fn get_subset_by_slider_position<'a, T, I>(
@@ -60,3 +93,2998 @@ pub fn get_start_end_pos(
     let end = (start + rows_fit).min(item_count);
     (start, end)
 }
+
+/// The amount of rows realized outside of the viewport of a list.
+///
+/// Realizing a few extra rows keeps fast scrolls from revealing empty space
+/// before the list catches up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Overscan {
+    /// The amount of rows realized above the viewport.
+    pub before: usize,
+    /// The amount of rows realized below the viewport.
+    pub after: usize,
+}
+
+impl Overscan {
+    /// Realizes no rows outside of the viewport.
+    pub const NONE: Self = Self {
+        before: 0,
+        after: 0,
+    };
+
+    /// Creates an [`Overscan`] of `rows` on both sides of the viewport.
+    pub fn new(rows: usize) -> Self {
+        Self {
+            before: rows,
+            after: rows,
+        }
+    }
+
+    /// Extends the range of rows `start..end` by the [`Overscan`], without
+    /// going past `item_count`.
+    pub fn apply(self, start: usize, end: usize, item_count: usize) -> (usize, usize) {
+        (
+            start.saturating_sub(self.before),
+            end.saturating_add(self.after).min(item_count),
+        )
+    }
+}
+
+/// Returns the range of rows to realize for a list scrolled `offset` pixels
+/// from its top.
+///
+/// Unlike [`get_start_end_pos`], the range is computed from the absolute
+/// offset, so it includes the rows partially visible at both edges. It is
+/// then extended by the given [`Overscan`].
+pub fn get_visible_range(
+    item_count: usize,
+    offset: f32,
+    row_height: f32,
+    view_height: f32,
+    overscan: Overscan,
+) -> (usize, usize) {
+    let offset = offset.max(0.0);
+    let start = ((offset / row_height).floor() as usize).min(item_count.saturating_sub(1));
+    let end = (((offset + view_height) / row_height).ceil() as usize).min(item_count);
+
+    overscan.apply(start, end.max(start), item_count)
+}
+
+/// The direction in which a [`FlatList`] lays out and scrolls its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    /// Rows are stacked from top to bottom.
+    #[default]
+    Vertical,
+    /// Rows are laid out from left to right, like the children of a `Row`.
+    Horizontal,
+}
+
+impl Axis {
+    /// Returns the length of `size` along the [`Axis`].
+    fn main(self, size: Size) -> f32 {
+        match self {
+            Axis::Vertical => size.height,
+            Axis::Horizontal => size.width,
+        }
+    }
+
+    /// Returns the length of `size` across the [`Axis`].
+    fn cross(self, size: Size) -> f32 {
+        match self {
+            Axis::Vertical => size.width,
+            Axis::Horizontal => size.height,
+        }
+    }
+
+    /// Builds a [`Size`] from its lengths along and across the [`Axis`].
+    fn size(self, main: f32, cross: f32) -> Size {
+        match self {
+            Axis::Vertical => Size::new(cross, main),
+            Axis::Horizontal => Size::new(main, cross),
+        }
+    }
+
+    /// Builds a [`Point`] from its coordinates along and across the [`Axis`].
+    fn point(self, main: f32, cross: f32) -> Point {
+        match self {
+            Axis::Vertical => Point::new(cross, main),
+            Axis::Horizontal => Point::new(main, cross),
+        }
+    }
+
+    /// Builds a [`Vector`] pointing `main` pixels along the [`Axis`].
+    fn vector(self, main: f32) -> Vector {
+        match self {
+            Axis::Vertical => Vector::new(0.0, main),
+            Axis::Horizontal => Vector::new(main, 0.0),
+        }
+    }
+}
+
+/// Where [`scroll_to_index`] places a row within the viewport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// The row is placed at the start of the viewport.
+    Start,
+    /// The row is centered in the viewport.
+    Center,
+    /// The row is placed at the end of the viewport.
+    End,
+    /// The list scrolls as little as possible to fully show the row.
+    #[default]
+    Nearest,
+}
+
+impl Align {
+    /// Returns the offset that aligns a row starting at `top` and `length`
+    /// pixels long in a viewport `view` pixels long, scrolled by `offset`.
+    fn offset(self, top: f32, length: f32, offset: f32, view: f32) -> f32 {
+        match self {
+            Align::Start => top,
+            Align::Center => top - (view - length) / 2.0,
+            Align::End => top + length - view,
+            Align::Nearest if top < offset || length > view => top,
+            Align::Nearest if top + length > offset + view => top + length - view,
+            Align::Nearest => offset,
+        }
+    }
+}
+
+/// Produces a [`Command`] that scrolls the [`FlatList`] with the given [`Id`]
+/// to the item at `index`, aligned as requested.
+///
+/// The offset of the item is resolved by the [`FlatList`] itself, using the
+/// heights of its rows.
+pub fn scroll_to_index<Message: 'static>(id: Id, index: usize, align: Align) -> Command<Message> {
+    Command::widget(ScrollToIndex {
+        target: id.0,
+        index,
+        align,
+        is_animated: false,
+    })
+}
+
+/// Produces a [`Command`] that scrolls the [`FlatList`] with the given [`Id`]
+/// to the item at `index` like [`scroll_to_index`], but animated as described
+/// by the [`Smooth`] of the [`FlatList`], or the default one.
+pub fn scroll_to_index_smoothly<Message: 'static>(
+    id: Id,
+    index: usize,
+    align: Align,
+) -> Command<Message> {
+    Command::widget(ScrollToIndex {
+        target: id.0,
+        index,
+        align,
+        is_animated: true,
+    })
+}
+
+/// The [`Operation`] behind [`scroll_to_index`].
+struct ScrollToIndex {
+    target: widget::Id,
+    index: usize,
+    align: Align,
+    is_animated: bool,
+}
+
+impl<T> Operation<T> for ScrollToIndex {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+        if id != Some(&self.target) {
+            return;
+        }
+
+        if let Some(request) = state.downcast_mut::<Request>() {
+            request.scroll_to = Some((self.index, self.align));
+            request.is_animated = self.is_animated;
+        }
+    }
+}
+
+/// What the operations run on a [`FlatList`] ask of it.
+#[derive(Debug, Default)]
+struct Request {
+    scroll_to: Option<(usize, Align)>,
+    is_animated: bool,
+}
+
+/// The [`Viewport`] of a [`FlatList`], along with the items it shows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListViewport {
+    /// The [`Viewport`] of the rows of the [`FlatList`].
+    pub viewport: Viewport,
+    /// The items at least partially visible, without [`Overscan`].
+    pub visible: Range<usize>,
+}
+
+/// How close to one of its ends the viewport of a [`FlatList`] needs to get
+/// before [`FlatList::on_start_reached`] or [`FlatList::on_end_reached`] fire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    /// At most this amount of rows are left between the viewport and the end.
+    Rows(usize),
+    /// At most this amount of pixels are left between the viewport and the
+    /// end.
+    Pixels(f32),
+}
+
+impl Threshold {
+    fn is_reached(self, rows: usize, pixels: f32) -> bool {
+        match self {
+            Threshold::Rows(threshold) => rows <= threshold,
+            Threshold::Pixels(threshold) => pixels <= threshold,
+        }
+    }
+}
+
+impl From<usize> for Threshold {
+    fn from(rows: usize) -> Self {
+        Threshold::Rows(rows)
+    }
+}
+
+impl From<f32> for Threshold {
+    fn from(pixels: f32) -> Self {
+        Threshold::Pixels(pixels)
+    }
+}
+
+/// How a [`FlatList`] splits its items into columns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Columns {
+    Fixed(usize),
+    MinWidth(f32),
+}
+
+/// A virtualized list that only builds the rows inside its viewport.
+///
+/// The [`FlatList`] owns its scroll offset, so it decides by itself which
+/// indices are visible and calls the row builder only for those.
+#[allow(missing_debug_implementations)]
+pub struct FlatList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet + selection::StyleSheet,
+{
+    id: Option<Id>,
+    width: Length,
+    height: Length,
+    item_count: usize,
+    row_height: f32,
+    variable_height: bool,
+    overscan: Overscan,
+    axis: Axis,
+    inverted: bool,
+    stick_to_end: bool,
+    columns: Columns,
+    vertical: Properties,
+    horizontal: Properties,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    auto_scroll: Option<AutoScroll>,
+    kinetic: Option<Kinetic>,
+    smooth: Option<Smooth>,
+    wheel: Wheel,
+    selection: selection::Mode,
+    selection_style: <Renderer::Theme as selection::StyleSheet>::Style,
+    header: Option<Element<'a, Message, Renderer>>,
+    sections: Option<Sections>,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    on_viewport_change: Option<Box<dyn Fn(ListViewport) -> Message + 'a>>,
+    on_focus_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_selection_change: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_start_reached: Option<(Threshold, Box<dyn Fn() -> Message + 'a>)>,
+    on_end_reached: Option<(Threshold, Box<dyn Fn() -> Message + 'a>)>,
+    source: Option<Source<'a, Message, Renderer>>,
+    content: RefCell<Content<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> FlatList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet + selection::StyleSheet,
+{
+    /// Creates a new [`FlatList`] with `item_count` rows of `row_height`
+    /// pixels each, built on demand by `view`.
+    ///
+    /// When the [`FlatList`] is [`Axis::Horizontal`], `row_height` is the
+    /// width of each row instead.
+    pub fn new(
+        item_count: usize,
+        row_height: f32,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            item_count,
+            row_height: row_height.max(1.0),
+            variable_height: false,
+            overscan: Overscan::new(DEFAULT_OVERSCAN),
+            axis: Axis::default(),
+            inverted: false,
+            stick_to_end: false,
+            columns: Columns::Fixed(1),
+            vertical: Properties::default(),
+            horizontal: Properties::default(),
+            style: Default::default(),
+            auto_scroll: None,
+            kinetic: None,
+            smooth: None,
+            wheel: Wheel::default(),
+            selection: selection::Mode::None,
+            selection_style: Default::default(),
+            header: None,
+            sections: None,
+            view: Box::new(view),
+            key: None,
+            on_viewport_change: None,
+            on_focus_change: None,
+            on_selection_change: None,
+            on_reorder: None,
+            on_start_reached: None,
+            on_end_reached: None,
+            source: None,
+            content: RefCell::new(Content {
+                window: None,
+                layout: layout::Node::new(Size::ZERO),
+                realized: 0..0,
+                rows: Vec::new(),
+                keys: Vec::new(),
+                is_diffed: false,
+                sticky: None,
+            }),
+        }
+    }
+
+    /// Creates a new [`FlatList`] grouped in [`Sections`], whose rows are
+    /// `row_height` pixels tall.
+    ///
+    /// The header of the section at the top of the viewport sticks to it
+    /// until the header of the next section pushes it away. Sectioned lists
+    /// always lay out one item per row.
+    pub fn sectioned(
+        sections: Sections,
+        row_height: f32,
+        header: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+        item: impl Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        let positions = sections.clone();
+
+        Self {
+            sections: Some(sections),
+            ..Self::new(positions.len(), row_height, move |index| {
+                match positions.position(index) {
+                    Some(Position::Header(section)) => header(section),
+                    Some(Position::Item { section, item: i }) => item(section, i),
+                    None => panic!("row {index} is out of the sections"),
+                }
+            })
+        }
+    }
+
+    /// Creates a new [`FlatList`] showing the items of a [`DataSource`] in rows
+    /// of `row_height` pixels.
+    ///
+    /// Items that are not loaded yet are shown as placeholder rows, a
+    /// [`Skeleton`] by default. Whenever the realized rows include some of
+    /// them, the [`FlatList`] produces the message built by `on_request` with
+    /// the range of items to load, which should be handed to
+    /// [`DataSource::request`].
+    pub fn with_source<S>(
+        source: &'a S,
+        row_height: f32,
+        view: impl Fn(S::Item) -> Element<'a, Message, Renderer> + 'a,
+        on_request: impl Fn(Range<usize>) -> Message + 'a,
+    ) -> Self
+    where
+        S: DataSource<Message>,
+        Message: 'a,
+        Renderer: 'a,
+        Renderer::Theme: skeleton::StyleSheet,
+    {
+        Self {
+            source: Some(Source {
+                is_loaded: Box::new(|index| source.is_loaded(index)),
+                on_request: Box::new(on_request),
+                placeholder: Box::new(|_| Skeleton::new().into()),
+            }),
+            ..Self::new(source.len(), row_height, move |index| {
+                match source.get(index) {
+                    Some(item) => view(item),
+                    None => Space::new(Length::Fill, Length::Fill).into(),
+                }
+            })
+        }
+    }
+
+    /// Identifies the items of the [`FlatList`] by a key that does not change
+    /// when other items are inserted or removed.
+    ///
+    /// The [`FlatList`] then keeps the first visible item at the same place
+    /// when the items change, so items inserted above the viewport do not
+    /// move the visible ones. The widget state of each row, like focus, also
+    /// stays with its item.
+    ///
+    /// Keys are compared by their 64-bit hash, not by equality, so two items
+    /// whose keys hash the same share their selection and their place as the
+    /// anchor of the viewport. The rows of such items keep their own widget
+    /// state, told apart by the order they appear in.
+    ///
+    /// Without a key, items are identified by their index.
+    pub fn key<K: Hash>(mut self, key: impl Fn(usize) -> K + 'a) -> Self {
+        self.key = Some(Box::new(move |index| {
+            let mut hasher = Hasher::default();
+            key(index).hash(&mut hasher);
+            hasher.finish()
+        }));
+        self
+    }
+
+    /// Sets the element shown in place of the items of the [`DataSource`] that
+    /// are not loaded yet.
+    ///
+    /// The placeholder is laid out at the height the row is expected to have,
+    /// so the rows do not move when the item replaces it. It has no effect
+    /// unless the [`FlatList`] was created with [`FlatList::with_source`].
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        if let Some(source) = &mut self.source {
+            source.placeholder = Box::new(placeholder);
+        }
+
+        self
+    }
+
+    /// Sets the [`Id`] of the [`FlatList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`FlatList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`FlatList`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Measures the rows of the [`FlatList`] instead of assuming they are all
+    /// `row_height` pixels tall.
+    ///
+    /// Rows that were never realized are estimated to be `row_height` pixels
+    /// tall until they scroll into view.
+    pub fn variable_height(mut self) -> Self {
+        self.variable_height = true;
+        self
+    }
+
+    /// Sets the [`Overscan`] of the [`FlatList`].
+    pub fn overscan(mut self, overscan: Overscan) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the [`Axis`] along which the [`FlatList`] lays out its rows.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Lays out the rows of the [`FlatList`] from the end of its [`Axis`],
+    /// so the first item is at the bottom, like the messages of a chat.
+    ///
+    /// An inverted [`FlatList`] starts scrolled to its first item and sticks
+    /// to it, as if [`FlatList::stick_to_end`] was set. Section headers do not
+    /// stick to an inverted [`FlatList`].
+    pub fn inverted(mut self) -> Self {
+        self.inverted = true;
+        self.stick_to_end = true;
+        self
+    }
+
+    /// Makes the [`FlatList`] start scrolled to the end and stay there as
+    /// items are added, unless it was scrolled away from the end.
+    pub fn stick_to_end(mut self) -> Self {
+        self.stick_to_end = true;
+        self
+    }
+
+    /// Lays out the items of the [`FlatList`] as a grid with the given amount
+    /// of columns.
+    ///
+    /// Every row of the grid is `row_height` pixels tall and the columns share
+    /// the available width evenly.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Columns::Fixed(columns.max(1));
+        self
+    }
+
+    /// Lays out the items of the [`FlatList`] as a grid with as many columns
+    /// of at least `width` pixels as the available width fits.
+    pub fn min_column_width(mut self, width: f32) -> Self {
+        self.columns = Columns::MinWidth(width.max(1.0));
+        self
+    }
+
+    /// Configures the vertical scrollbar of the [`FlatList`].
+    pub fn vertical_scroll(mut self, properties: Properties) -> Self {
+        self.vertical = properties;
+        self
+    }
+
+    /// Configures the horizontal scrollbar of an [`Axis::Horizontal`]
+    /// [`FlatList`].
+    pub fn horizontal_scroll(mut self, properties: Properties) -> Self {
+        self.horizontal = properties;
+        self
+    }
+
+    /// Sets a function to call when the [`ListViewport`] of the [`FlatList`]
+    /// changes, either because it scrolled or because it was resized.
+    pub fn on_viewport_change(mut self, f: impl Fn(ListViewport) -> Message + 'a) -> Self {
+        self.on_viewport_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a function to call when the focused item of the [`FlatList`]
+    /// changes.
+    ///
+    /// Clicking an item focuses it and gives the [`FlatList`] the keyboard
+    /// focus. The arrow keys, PageUp, PageDown, Home and End then move the
+    /// focus, scrolling to keep the focused item visible.
+    pub fn on_focus_change(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_focus_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets how many items of the [`FlatList`] can be selected at once.
+    ///
+    /// Selected items are remembered by their key, so they stay selected
+    /// when items are inserted or removed before them.
+    pub fn selection(mut self, mode: selection::Mode) -> Self {
+        self.selection = mode;
+        self
+    }
+
+    /// Sets the style of the selected rows of the [`FlatList`].
+    pub fn selection_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as selection::StyleSheet>::Style>,
+    ) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets a function to call with the indices of the selected items, in
+    /// order, when the selection of the [`FlatList`] changes.
+    pub fn on_selection_change(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_selection_change = Some(Box::new(f));
+        self
+    }
+
+    /// Scrolls the [`FlatList`] while the mouse button is held close to its
+    /// edges.
+    ///
+    /// Lists with [`on_reorder`](Self::on_reorder) use the default
+    /// [`AutoScroll`] unless told otherwise.
+    pub fn auto_scroll(mut self, auto_scroll: AutoScroll) -> Self {
+        self.auto_scroll = Some(auto_scroll);
+        self
+    }
+
+    /// Makes the [`FlatList`] keep scrolling after it is flung with a finger,
+    /// slowing down as described by the given [`Kinetic`].
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.kinetic = Some(kinetic);
+        self
+    }
+
+    /// Animates the scrolls of the [`FlatList`] by lines of the mouse wheel
+    /// and by keyboard navigation, as described by the given [`Smooth`].
+    pub fn smooth(mut self, smooth: Smooth) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets how the [`FlatList`] scrolls with the mouse wheel.
+    ///
+    /// Use [`Wheel::rows`] with the height of the rows to scroll a list of
+    /// fixed height rows by whole rows.
+    pub fn wheel(mut self, wheel: Wheel) -> Self {
+        self.wheel = wheel;
+        self
+    }
+
+    /// Lets the rows of the [`FlatList`] be reordered by dragging them, and
+    /// sets the function to call with the index of the dragged item and the
+    /// index it should be moved to once dropped.
+    ///
+    /// The new index is counted as if the item was already removed from its
+    /// place, so it can be handled with a [`Vec::remove`] followed by a
+    /// [`Vec::insert`].
+    ///
+    /// Rows that capture the press of the mouse button, like buttons, cannot
+    /// be dragged.
+    pub fn on_reorder(mut self, f: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to produce when the viewport gets within `threshold`
+    /// of the start of the [`FlatList`].
+    ///
+    /// The message is produced once, and then not again until the amount of
+    /// items changes.
+    pub fn on_start_reached(mut self, threshold: impl Into<Threshold>, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_start_reached = Some((threshold.into(), Box::new(move || message.clone())));
+        self
+    }
+
+    /// Sets the message to produce when the viewport gets within `threshold`
+    /// of the end of the [`FlatList`], usually to load more items.
+    ///
+    /// The message is produced once, and then not again until the amount of
+    /// items changes.
+    pub fn on_end_reached(mut self, threshold: impl Into<Threshold>, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_end_reached = Some((threshold.into(), Box::new(move || message.clone())));
+        self
+    }
+
+    /// Sets the style of the [`FlatList`].
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the header of the [`FlatList`].
+    ///
+    /// The header is pinned above the rows, so it stays in place while they
+    /// scroll and is not part of the scrollable height.
+    pub fn header(mut self, header: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Returns the amount of columns that fit in `cross` pixels.
+    fn column_count(&self, cross: f32) -> usize {
+        if self.sections.is_some() {
+            return 1;
+        }
+
+        match self.columns {
+            Columns::Fixed(columns) => columns,
+            Columns::MinWidth(width) => ((cross / width).floor() as usize).max(1),
+        }
+    }
+
+    /// Returns the amount of rows needed to hold every item in `columns`.
+    fn row_count(&self, columns: usize) -> usize {
+        self.item_count.div_ceil(columns)
+    }
+
+    /// Returns the range of items laid out in the given range of rows.
+    fn items(&self, rows: Range<usize>, columns: usize) -> Range<usize> {
+        (rows.start * columns).min(self.item_count)..(rows.end * columns).min(self.item_count)
+    }
+
+    fn content_bounds(&self, heights: &RowHeights, bounds: Rectangle) -> Rectangle {
+        let size = self
+            .axis
+            .size(heights.total(), self.axis.cross(bounds.size()));
+
+        Rectangle {
+            width: size.width,
+            height: size.height,
+            ..bounds
+        }
+    }
+
+    fn offset(
+        &self,
+        scrollable: &new_scrollable::State,
+        content_bounds: Rectangle,
+        bounds: Rectangle,
+    ) -> f32 {
+        let offset = self
+            .axis
+            .main(Size::from(scrollable.offset(bounds, content_bounds)));
+
+        // The offset of an inverted list is measured from the end, so the
+        // rest of the list can ignore the inversion.
+        if self.inverted {
+            (self.axis.main(content_bounds.size()) - self.axis.main(bounds.size())).max(0.0)
+                - offset
+        } else {
+            offset
+        }
+    }
+
+    /// Returns where a row starting `start` pixels into the viewport and
+    /// `length` pixels long is laid out, given the viewport is `view` pixels
+    /// long.
+    fn row_position(&self, start: f32, length: f32, view: f32) -> f32 {
+        if self.inverted {
+            view - start - length
+        } else {
+            start
+        }
+    }
+
+    /// Scrolls the [`FlatList`] to `offset` pixels along its [`Axis`].
+    fn scroll_to(
+        &self,
+        scrollable: &mut new_scrollable::State,
+        heights: &RowHeights,
+        bounds: Rectangle,
+        offset: f32,
+    ) {
+        let content_bounds = self.content_bounds(heights, bounds);
+        let current = self.offset(scrollable, content_bounds, bounds);
+
+        if current != offset {
+            let delta = if self.inverted {
+                offset - current
+            } else {
+                current - offset
+            };
+
+            scrollable.scroll(self.axis.vector(delta), bounds, content_bounds);
+        }
+    }
+
+    /// Scrolls the [`FlatList`] to `offset` pixels along its [`Axis`] with an
+    /// animation.
+    fn scroll_smoothly_to(
+        &self,
+        scrollable: &mut new_scrollable::State,
+        heights: &RowHeights,
+        bounds: Rectangle,
+        offset: f32,
+    ) {
+        let content_bounds = self.content_bounds(heights, bounds);
+        let current = self.offset(scrollable, content_bounds, bounds);
+        let delta = if self.inverted {
+            offset - current
+        } else {
+            current - offset
+        };
+
+        scrollable.scroll_smoothly_to(
+            scrollable.offset(bounds, content_bounds) - self.axis.vector(delta),
+            bounds,
+            content_bounds,
+        );
+    }
+
+    /// Regroups the rows after the amount of columns changed, keeping the
+    /// first visible item where it was.
+    fn regroup(
+        &self,
+        scrollable: &mut new_scrollable::State,
+        heights: &mut RowHeights,
+        bounds: Rectangle,
+        previous: usize,
+        columns: usize,
+    ) {
+        let offset = self.offset(scrollable, self.content_bounds(heights, bounds), bounds);
+        let row = heights.index_at(offset);
+        let within = offset - heights.offset_of(row);
+        let item = row * previous;
+
+        // Measurements belong to the old rows, so they are all discarded.
+        *heights = RowHeights::new(self.row_count(columns), self.row_height);
+
+        let row = (item / columns).min(heights.len().saturating_sub(1));
+        let within = if heights.len() > 0 {
+            within.min(heights.height(row))
+        } else {
+            0.0
+        };
+
+        self.scroll_to(scrollable, heights, bounds, heights.offset_of(row) + within);
+    }
+
+    /// Scrolls the [`FlatList`] until the item at `index` is aligned within
+    /// the viewport, with an animation if `is_animated`.
+    fn reveal(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        index: usize,
+        align: Align,
+        is_animated: bool,
+    ) {
+        if self.item_count == 0 {
+            return;
+        }
+
+        let heights = state.heights.borrow();
+        let mut scrollable = state.scrollable.borrow_mut();
+
+        let row = index.min(self.item_count - 1) / state.columns.get().max(1);
+        let current = self.offset(&scrollable, self.content_bounds(&heights, bounds), bounds);
+        let offset = align.offset(
+            heights.offset_of(row),
+            heights.height(row),
+            current,
+            self.axis.main(bounds.size()),
+        );
+
+        if is_animated {
+            self.scroll_smoothly_to(&mut scrollable, &heights, bounds, offset);
+        } else {
+            self.scroll_to(&mut scrollable, &heights, bounds, offset);
+        }
+    }
+
+    /// Returns the [`ListViewport`] of the [`FlatList`] in the given bounds.
+    fn viewport(&self, state: &State, bounds: Rectangle) -> ListViewport {
+        let heights = state.heights.borrow();
+        let scrollable = state.scrollable.borrow();
+        let content_bounds = self.content_bounds(&heights, bounds);
+        let viewport = Viewport::new(&scrollable, bounds, content_bounds);
+
+        let offset = self.offset(&scrollable, content_bounds, bounds);
+        let window = visible_window(
+            &heights,
+            offset,
+            self.axis.main(bounds.size()),
+            bounds.size(),
+            Overscan::NONE,
+        );
+
+        ListViewport {
+            viewport,
+            visible: self.items(window.start..window.end, state.columns.get().max(1)),
+        }
+    }
+
+    /// Publishes the [`ListViewport`] of the [`FlatList`] if it changed since
+    /// the last time.
+    fn notify_on_viewport_change(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(on_viewport_change) = &self.on_viewport_change {
+            let viewport = self.viewport(state, bounds);
+
+            if state.last_viewport.as_ref() != Some(&viewport) {
+                shell.publish(on_viewport_change(viewport.clone()));
+                state.last_viewport = Some(viewport);
+            }
+        }
+    }
+
+    /// Produces the messages of [`FlatList::on_start_reached`] and
+    /// [`FlatList::on_end_reached`] once the viewport gets close enough to
+    /// either end.
+    fn notify_on_edges(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.on_start_reached.is_none() && self.on_end_reached.is_none() {
+            return;
+        }
+
+        let (start, end) = {
+            let heights = state.heights.borrow();
+            let content_bounds = self.content_bounds(&heights, bounds);
+            let offset = self.offset(&state.scrollable.borrow(), content_bounds, bounds);
+            let length = self.axis.main(bounds.size());
+            let window = visible_window(&heights, offset, length, bounds.size(), Overscan::NONE);
+
+            (
+                (window.start, offset),
+                (
+                    heights.len() - window.end,
+                    (heights.total() - offset - length).max(0.0),
+                ),
+            )
+        };
+
+        let edges = [
+            (&self.on_start_reached, start, &mut state.start_reached_at),
+            (&self.on_end_reached, end, &mut state.end_reached_at),
+        ];
+
+        for (callback, (rows, pixels), reached_at) in edges {
+            let Some((threshold, message)) = callback else {
+                continue;
+            };
+
+            if *reached_at != Some(self.item_count) && threshold.is_reached(rows, pixels) {
+                shell.publish(message());
+                *reached_at = Some(self.item_count);
+            }
+        }
+    }
+
+    /// Returns `true` if the item at `index` is shown as a placeholder.
+    fn is_placeholder(&self, index: usize) -> bool {
+        self.source
+            .as_ref()
+            .is_some_and(|source| !(source.is_loaded)(index))
+    }
+
+    /// Returns the key of the item at `index`, or the index itself if the
+    /// items have no keys.
+    fn item_key(&self, index: usize) -> u64 {
+        match &self.key {
+            Some(key) => key(index),
+            None => index as u64,
+        }
+    }
+
+    /// Builds the row of the item at `index`.
+    fn row(&self, index: usize) -> Element<'a, Message, Renderer> {
+        match &self.source {
+            Some(source) if !(source.is_loaded)(index) => (source.placeholder)(index),
+            _ => (self.view)(index),
+        }
+    }
+
+    /// Asks for the items missing from the realized rows to be loaded, unless
+    /// they were already asked for.
+    fn request_missing(
+        &self,
+        state: &mut State,
+        realized: Range<usize>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(source) = &self.source else {
+            return;
+        };
+
+        let mut missing = realized.filter(|&index| !(source.is_loaded)(index));
+
+        let Some(first) = missing.next() else {
+            return;
+        };
+        let missing = first..missing.next_back().unwrap_or(first) + 1;
+
+        if state.requested.as_ref() != Some(&missing) {
+            shell.publish((source.on_request)(missing.clone()));
+            state.requested = Some(missing);
+        }
+    }
+
+    /// Creates the [`new_scrollable::State`] of the [`FlatList`].
+    fn scrollable(&self) -> new_scrollable::State {
+        let mut scrollable = new_scrollable::State::new();
+
+        if self.stick_to_end {
+            scrollable.stick_to_end();
+        }
+
+        scrollable.set_kinetic(self.kinetic);
+        scrollable.set_smooth(self.smooth);
+        scrollable.set_wheel(self.scroll_wheel());
+        scrollable
+    }
+
+    /// Returns the item under `point`, if any.
+    fn item_at(&self, state: &State, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) || self.item_count == 0 {
+            return None;
+        }
+
+        let heights = state.heights.borrow();
+        let content_bounds = self.content_bounds(&heights, bounds);
+        let offset = self.offset(&state.scrollable.borrow(), content_bounds, bounds);
+
+        let position = Size::new(point.x - bounds.x, point.y - bounds.y);
+        let along = self.axis.main(position);
+        let along = self.row_position(along, 0.0, self.axis.main(bounds.size()));
+
+        // A short list leaves empty space past its last row.
+        if offset + along >= heights.total() {
+            return None;
+        }
+
+        let columns = state.columns.get().max(1);
+        let cell = self.axis.cross(bounds.size()) / columns as f32;
+        let column = ((self.axis.cross(position) / cell) as usize).min(columns - 1);
+        let index = heights.index_at(offset + along) * columns + column;
+
+        (index < self.item_count).then_some(index)
+    }
+
+    /// Returns the item that `key_code` moves the focus to, if it is a
+    /// navigation key.
+    fn navigate(&self, state: &State, bounds: Rectangle, key_code: KeyCode) -> Option<usize> {
+        if self.item_count == 0 {
+            return None;
+        }
+
+        let last = self.item_count - 1;
+        let columns = state.columns.get().max(1);
+        let visible = self.viewport(state, bounds).visible;
+        let page = visible.len().max(1);
+
+        let (back, forward, previous, next) = match self.axis {
+            Axis::Vertical => (KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right),
+            Axis::Horizontal => (KeyCode::Left, KeyCode::Right, KeyCode::Up, KeyCode::Down),
+        };
+
+        // An inverted list runs backwards, so do its keys.
+        let (back, forward, page_back, page_forward) = if self.inverted {
+            (forward, back, KeyCode::PageDown, KeyCode::PageUp)
+        } else {
+            (back, forward, KeyCode::PageUp, KeyCode::PageDown)
+        };
+
+        let step = |current: usize| -> Option<usize> {
+            Some(match key_code {
+                key if key == back => current.saturating_sub(columns),
+                key if key == forward => current + columns,
+                key if key == previous && columns > 1 => current.saturating_sub(1),
+                key if key == next && columns > 1 => current + 1,
+                key if key == page_back => current.saturating_sub(page),
+                key if key == page_forward => current + page,
+                KeyCode::Home => 0,
+                KeyCode::End => last,
+                _ => return None,
+            })
+        };
+
+        let index = step(state.focus.index.unwrap_or(visible.start))?;
+
+        // The first key press focuses the first visible item, unless it jumps
+        // to either end.
+        Some(match state.focus.index {
+            None if !matches!(key_code, KeyCode::Home | KeyCode::End) => visible.start,
+            _ => index,
+        })
+        .map(|index| index.min(last))
+    }
+
+    /// Focuses the item at `index`.
+    fn focus(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        if state.focus.index != Some(index) {
+            state.focus.index = Some(index);
+
+            if let Some(on_focus_change) = &self.on_focus_change {
+                shell.publish(on_focus_change(index));
+            }
+        }
+    }
+
+    /// Returns `true` if the item at `index` can be selected.
+    fn is_selectable(&self, index: usize) -> bool {
+        !self
+            .sections
+            .as_ref()
+            .is_some_and(|sections| matches!(sections.position(index), Some(Position::Header(_))))
+    }
+
+    /// Returns `true` if the item at `index` is selected.
+    fn is_selected(&self, state: &State, index: usize) -> bool {
+        state.selected.contains(&self.item_key(index))
+    }
+
+    /// Returns the indices of the selected items, in order.
+    fn selected_indices(&self, selected: &HashSet<u64>) -> Vec<usize> {
+        if self.key.is_some() {
+            return (0..self.item_count)
+                .filter(|&index| selected.contains(&self.item_key(index)))
+                .collect();
+        }
+
+        let mut indices: Vec<usize> = selected
+            .iter()
+            .map(|&key| key as usize)
+            .filter(|&index| index < self.item_count)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Replaces the selection of the [`FlatList`], publishing it if it
+    /// changed.
+    fn set_selection(
+        &self,
+        state: &mut State,
+        selected: HashSet<u64>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if selected == state.selected {
+            return;
+        }
+
+        if let Some(on_selection_change) = &self.on_selection_change {
+            shell.publish(on_selection_change(self.selected_indices(&selected)));
+        }
+
+        state.selected = selected;
+    }
+
+    /// Selects the item at `index` as a click with the current keyboard
+    /// modifiers would.
+    fn select(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        if !self.is_selectable(index) {
+            return;
+        }
+
+        let modifiers = state.scrollable.get_mut().keyboard_modifiers();
+        let key = self.item_key(index);
+        let mut selected = state.selected.clone();
+
+        match self.selection {
+            selection::Mode::None => return,
+            selection::Mode::Single => {
+                let is_selected = selected.contains(&key);
+
+                selected.clear();
+
+                if !(is_selected && modifiers.command()) {
+                    selected.insert(key);
+                }
+            }
+            selection::Mode::Multiple if modifiers.shift() => {
+                let anchor = state.selection_anchor.unwrap_or(index);
+                let range = anchor.min(index)..=anchor.max(index);
+
+                if !modifiers.command() {
+                    selected.clear();
+                }
+
+                selected.extend(
+                    range
+                        .filter(|&index| self.is_selectable(index))
+                        .map(|index| self.item_key(index)),
+                );
+            }
+            selection::Mode::Multiple => {
+                if !modifiers.command() {
+                    selected.clear();
+                }
+
+                if !selected.remove(&key) {
+                    selected.insert(key);
+                }
+
+                state.selection_anchor = Some(index);
+            }
+        }
+
+        self.set_selection(state, selected, shell);
+    }
+
+    /// Selects every item of the [`FlatList`].
+    fn select_all(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let selected = (0..self.item_count)
+            .filter(|&index| self.is_selectable(index))
+            .map(|index| self.item_key(index))
+            .collect();
+
+        self.set_selection(state, selected, shell);
+    }
+
+    /// Focuses and selects the item under a click, if any.
+    ///
+    /// Rows that capture the click still get the focus, but keep the
+    /// selection as it is.
+    fn click(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        cursor_position: Point,
+        item: Option<usize>,
+        status: event::Status,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.focus.is_focused = bounds.contains(cursor_position);
+
+        if state.scrollable.get_mut().scrollers_grabbed() {
+            return;
+        }
+
+        if let Some(index) = item {
+            self.focus(state, index, shell);
+
+            if status == event::Status::Ignored {
+                self.select(state, index, shell);
+
+                if self.on_reorder.is_some() && self.is_selectable(index) {
+                    state.drag = Some(Drag {
+                        index,
+                        bounds: self.cell_bounds(state, bounds, index),
+                        grabbed_at: cursor_position,
+                        cursor_position,
+                        is_dragging: false,
+                        gap: index,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns the bounds of the cell of the item at `index`, whether it is
+    /// realized or not.
+    fn cell_bounds(&self, state: &State, bounds: Rectangle, index: usize) -> Rectangle {
+        let heights = state.heights.borrow();
+        let content_bounds = self.content_bounds(&heights, bounds);
+        let offset = self.offset(&state.scrollable.borrow(), content_bounds, bounds);
+
+        let columns = state.columns.get().max(1);
+        let cell = self.axis.cross(bounds.size()) / columns as f32;
+        let (row, column) = (index / columns, index % columns);
+
+        let length = heights.height(row);
+        let start = self.row_position(
+            heights.offset_of(row) - offset,
+            length,
+            self.axis.main(bounds.size()),
+        );
+
+        Rectangle::new(
+            bounds.position() + (self.axis.point(start, column as f32 * cell) - Point::ORIGIN),
+            self.axis.size(length, cell),
+        )
+    }
+
+    /// Returns the gap between items closest to `point`, as the index of the
+    /// item after it.
+    fn gap_at(&self, state: &State, bounds: Rectangle, point: Point) -> usize {
+        let heights = state.heights.borrow();
+
+        if heights.len() == 0 {
+            return 0;
+        }
+
+        let content_bounds = self.content_bounds(&heights, bounds);
+        let offset = self.offset(&state.scrollable.borrow(), content_bounds, bounds);
+        let view = self.axis.main(bounds.size());
+
+        let position = Size::new(point.x - bounds.x, point.y - bounds.y);
+        let along = self.axis.main(position).clamp(0.0, view);
+        let along = offset + self.row_position(along, 0.0, view);
+        let row = heights.index_at(along).min(heights.len() - 1);
+
+        let columns = state.columns.get().max(1);
+
+        let gap = if columns == 1 {
+            let middle = heights.offset_of(row) + heights.height(row) / 2.0;
+
+            row + usize::from(along > middle)
+        } else {
+            let cross = self.axis.cross(bounds.size());
+            let cell = cross / columns as f32;
+            let across = self.axis.cross(position).clamp(0.0, cross);
+            let column = ((across / cell) as usize).min(columns - 1);
+
+            row * columns + column + usize::from(across > (column as f32 + 0.5) * cell)
+        };
+
+        gap.min(self.item_count)
+    }
+
+    /// Moves the row being dragged, if any, to `cursor_position`.
+    fn drag(&self, state: &mut State, bounds: Rectangle, cursor_position: Point) -> event::Status {
+        let Some(mut drag) = state.drag else {
+            return event::Status::Ignored;
+        };
+
+        drag.cursor_position = cursor_position;
+
+        if !drag.is_dragging {
+            let distance = drag.grabbed_at.distance(cursor_position);
+
+            if distance < DRAG_THRESHOLD {
+                state.drag = Some(drag);
+                return event::Status::Ignored;
+            }
+
+            drag.is_dragging = true;
+        }
+
+        drag.gap = self.gap_at(state, bounds, cursor_position);
+        state.drag = Some(drag);
+
+        event::Status::Captured
+    }
+
+    /// Drops the row being dragged, if any.
+    fn release(&self, state: &mut State, shell: &mut Shell<'_, Message>) -> event::Status {
+        let Some(drag) = state.drag.take().filter(|drag| drag.is_dragging) else {
+            return event::Status::Ignored;
+        };
+
+        let to = if drag.gap > drag.index {
+            drag.gap - 1
+        } else {
+            drag.gap
+        };
+
+        if let Some(on_reorder) = &self.on_reorder {
+            if to != drag.index && drag.index < self.item_count {
+                shell.publish(on_reorder(drag.index, to));
+            }
+        }
+
+        event::Status::Captured
+    }
+
+    /// Returns the line showing where the dragged row would be dropped,
+    /// given the layouts of the realized rows.
+    fn drop_indicator(
+        &self,
+        gap: usize,
+        realized: &Range<usize>,
+        columns: usize,
+        layout: Layout<'_>,
+    ) -> Option<Rectangle> {
+        let (index, is_before) = if realized.contains(&gap) {
+            (gap, true)
+        } else if gap > 0 && realized.contains(&(gap - 1)) {
+            (gap - 1, false)
+        } else {
+            return None;
+        };
+
+        let bounds = layout.children().nth(index - realized.start)?.bounds();
+
+        // Rows stack along the axis, but the items of a row sit side by side
+        // across it.
+        let is_along = columns == 1;
+        let is_start = is_before != (is_along && self.inverted);
+
+        let vertical_line = (self.axis == Axis::Horizontal) == is_along;
+        let half = DROP_INDICATOR_WIDTH / 2.0;
+
+        Some(if vertical_line {
+            let x = if is_start {
+                bounds.x
+            } else {
+                bounds.x + bounds.width
+            };
+
+            Rectangle {
+                x: x - half,
+                width: DROP_INDICATOR_WIDTH,
+                ..bounds
+            }
+        } else {
+            let y = if is_start {
+                bounds.y
+            } else {
+                bounds.y + bounds.height
+            };
+
+            Rectangle {
+                y: y - half,
+                height: DROP_INDICATOR_WIDTH,
+                ..bounds
+            }
+        })
+    }
+
+    /// Handles a key press ignored by the rows of a focused [`FlatList`].
+    fn key_press(
+        &self,
+        state: &mut State,
+        bounds: Rectangle,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if key_code == KeyCode::A
+            && modifiers.command()
+            && self.selection == selection::Mode::Multiple
+        {
+            self.select_all(state, shell);
+
+            return event::Status::Captured;
+        }
+
+        let Some(index) = self.navigate(state, bounds, key_code) else {
+            return event::Status::Ignored;
+        };
+
+        self.focus(state, index, shell);
+        self.reveal(state, bounds, index, Align::Nearest, self.smooth.is_some());
+
+        if state.scrollable.get_mut().is_animating() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Captured
+    }
+
+    /// Returns the [`Properties`] of the horizontal scrollbar, if any.
+    fn horizontal(&self) -> Option<&Properties> {
+        match self.axis {
+            Axis::Vertical => None,
+            Axis::Horizontal => Some(&self.horizontal),
+        }
+    }
+
+    /// Returns how the scrollable of the [`FlatList`] turns the mouse wheel
+    /// into scrolling.
+    ///
+    /// A horizontal list already scrolls sideways with the lines of a regular
+    /// mouse wheel, so holding Shift must not swap them back. The rows of an
+    /// inverted list are lined up with its end.
+    fn scroll_wheel(&self) -> Wheel {
+        let wheel = self.wheel.snap_from_end(self.inverted);
+
+        match self.axis {
+            Axis::Vertical => wheel,
+            Axis::Horizontal => wheel.shift_for_horizontal(false),
+        }
+    }
+
+    /// Splits the layout of the [`FlatList`] into the layout of its header,
+    /// if any, and the layout of the viewport the rows scroll in.
+    fn split<'b>(&self, layout: Layout<'b>) -> (Option<Layout<'b>>, Layout<'b>) {
+        let mut children = layout.children();
+        let header = self.header.as_ref().and_then(|_| children.next());
+
+        (header, children.next().unwrap())
+    }
+
+    /// Returns the [`Anchor`] of the first visible item, if any.
+    fn anchor(
+        &self,
+        scrollable: &new_scrollable::State,
+        heights: &RowHeights,
+        bounds: Rectangle,
+        columns: usize,
+    ) -> Option<Anchor> {
+        if self.item_count == 0 {
+            return None;
+        }
+
+        let offset = self.offset(scrollable, self.content_bounds(heights, bounds), bounds);
+        let row = heights.index_at(offset);
+        let index = (row * columns).min(self.item_count - 1);
+
+        Some(Anchor {
+            key: self.item_key(index),
+            index,
+            item_count: self.item_count,
+            within: offset - heights.offset_of(row),
+        })
+    }
+
+    /// Returns the current index of the item of the [`Anchor`], if it is still
+    /// around.
+    fn find_anchor(&self, anchor: Anchor) -> Option<usize> {
+        let shifted = (anchor.index + self.item_count).checked_sub(anchor.item_count);
+
+        // The item most likely stayed where it was or moved by as many items
+        // as were inserted, so those are checked before looking everywhere.
+        [Some(anchor.index), shifted]
+            .into_iter()
+            .flatten()
+            .filter(|&index| index < self.item_count)
+            .chain(0..self.item_count)
+            .find(|&index| self.item_key(index) == anchor.key)
+    }
+
+    /// Resizes the rows to the current items, scrolling to keep the item of
+    /// the [`Anchor`] where it was if the items changed.
+    ///
+    /// A [`FlatList`] stuck to its end stays there instead.
+    fn follow_anchor(
+        &self,
+        anchor: Option<Anchor>,
+        scrollable: &mut new_scrollable::State,
+        heights: &mut RowHeights,
+        bounds: Rectangle,
+        columns: usize,
+    ) {
+        let moved = anchor.and_then(|anchor| {
+            let index = self.find_anchor(anchor)?;
+
+            (index != anchor.index || self.item_count != anchor.item_count)
+                .then_some((anchor, index))
+        });
+
+        // Measurements follow their rows when a single column moves, as
+        // the new rows were inserted or removed before the anchor.
+        if let Some((anchor, index)) = moved.filter(|_| columns == 1) {
+            if index > anchor.index {
+                heights.insert(anchor.index, index - anchor.index);
+            } else if index < anchor.index {
+                heights.remove(index, anchor.index - index);
+            }
+        }
+
+        heights.resize(self.row_count(columns), self.row_height);
+
+        let is_stuck = scrollable.sticks_to_end()
+            && scrollable.is_at_end(bounds, self.content_bounds(heights, bounds));
+
+        if let Some((anchor, index)) = moved.filter(|_| !is_stuck) {
+            let row = index / columns;
+
+            self.scroll_to(
+                scrollable,
+                heights,
+                bounds,
+                heights.offset_of(row) + anchor.within,
+            );
+        }
+    }
+
+    /// Brings the realized rows up to date with the current scroll offset.
+    fn content(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) -> RefMut<'_, Content<'a, Message, Renderer>> {
+        let mut content = self.content.borrow_mut();
+        let mut heights = state.heights.borrow_mut();
+        let columns = self.column_count(self.axis.cross(bounds.size()));
+        let previous = state.columns.replace(columns);
+
+        if previous != columns && previous != 0 {
+            self.regroup(
+                &mut state.scrollable.borrow_mut(),
+                &mut heights,
+                bounds,
+                previous,
+                columns,
+            );
+        } else {
+            self.follow_anchor(
+                state.anchor.get(),
+                &mut state.scrollable.borrow_mut(),
+                &mut heights,
+                bounds,
+                columns,
+            );
+        }
+
+        content.update(
+            &mut state.scrollable.borrow_mut(),
+            &mut heights,
+            renderer,
+            bounds,
+            self,
+        );
+        content.diff(&mut state.tree.borrow_mut(), &mut state.keys.borrow_mut());
+        state
+            .anchor
+            .set(self.anchor(&state.scrollable.borrow(), &heights, bounds, columns));
+        content.stick(&mut state.sticky.borrow_mut(), &heights, renderer, self);
+
+        content
+    }
+}
+
+/// The first visible item of a [`FlatList`] and how far the [`FlatList`] is
+/// scrolled past it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Anchor {
+    key: u64,
+    index: usize,
+    item_count: usize,
+    within: f32,
+}
+
+/// The distance, in pixels, the cursor travels before a pressed row starts
+/// being dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The width of the line showing where a dragged row would be dropped.
+const DROP_INDICATOR_WIDTH: f32 = 2.0;
+
+/// A row pressed, and possibly being dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    /// The bounds of the row when it was pressed.
+    bounds: Rectangle,
+    grabbed_at: Point,
+    cursor_position: Point,
+    is_dragging: bool,
+    /// The gap where the row would be dropped.
+    gap: usize,
+}
+
+impl Drag {
+    /// Returns where the floating copy of the row is drawn, following the
+    /// cursor along the [`Axis`].
+    fn position(&self, axis: Axis) -> Point {
+        let moved = self.cursor_position - self.grabbed_at;
+
+        self.bounds.position()
+            + match axis {
+                Axis::Vertical => Vector::new(0.0, moved.y),
+                Axis::Horizontal => Vector::new(moved.x, 0.0),
+            }
+    }
+}
+
+/// The floating copy of a dragged row.
+struct Dragged<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: selection::StyleSheet,
+{
+    row: Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    style: &'b <Renderer::Theme as selection::StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Dragged<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: selection::StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> layout::Node {
+        let limits = layout::Limits::new(self.size, self.size);
+        let mut node = self.row.as_widget().layout(renderer, &limits);
+
+        node.move_to(position);
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = <Renderer::Theme as selection::StyleSheet>::selected(theme, self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        self.row.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // The copy always sits under the cursor, so the list below must keep
+        // getting it to know where the row is dropped.
+        false
+    }
+}
+
+/// The keyboard focus of a [`FlatList`] and the item it is on.
+#[derive(Debug, Clone, Copy, Default)]
+struct Focus {
+    is_focused: bool,
+    index: Option<usize>,
+}
+
+impl Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The [`DataSource`] behind a [`FlatList`].
+struct Source<'a, Message, Renderer> {
+    is_loaded: Box<dyn Fn(usize) -> bool + 'a>,
+    on_request: Box<dyn Fn(Range<usize>) -> Message + 'a>,
+    placeholder: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+}
+
+struct Content<'a, Message, Renderer> {
+    window: Option<Window>,
+    layout: layout::Node,
+    realized: Range<usize>,
+    rows: Vec<Element<'a, Message, Renderer>>,
+    keys: Vec<u64>,
+    is_diffed: bool,
+    sticky: Option<Sticky<'a, Message, Renderer>>,
+}
+
+/// The header pinned to the top of a sectioned [`FlatList`].
+struct Sticky<'a, Message, Renderer> {
+    index: usize,
+    element: Element<'a, Message, Renderer>,
+    layout: layout::Node,
+}
+
+/// The rows realized by a [`FlatList`] and the viewport they were laid out in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Window {
+    start: usize,
+    end: usize,
+    offset: f32,
+    size: Size,
+}
+
+impl<'a, Message, Renderer> Sticky<'a, Message, Renderer> {
+    /// Returns the layout of the header in a viewport with the given bounds.
+    fn layout(&self, bounds: Rectangle) -> Layout<'_> {
+        Layout::with_offset(bounds.position() - Point::ORIGIN, &self.layout)
+    }
+
+    /// Returns `true` if the visible part of the header contains the cursor.
+    fn contains(&self, bounds: Rectangle, cursor_position: Point) -> bool {
+        bounds.contains(cursor_position) && self.layout(bounds).bounds().contains(cursor_position)
+    }
+
+    /// Hides the cursor from the header while it is outside of the viewport.
+    fn cursor_position(&self, bounds: Rectangle, cursor_position: Point) -> Point {
+        if bounds.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet + selection::StyleSheet,
+{
+    fn update(
+        &mut self,
+        scrollable: &mut new_scrollable::State,
+        heights: &mut RowHeights,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        list: &FlatList<'a, Message, Renderer>,
+    ) {
+        if list.variable_height {
+            self.measure(scrollable, heights, renderer, bounds, list);
+
+            return;
+        }
+
+        let size = bounds.size();
+        let axis = list.axis;
+        let columns = list.column_count(axis.cross(size));
+        let cell = axis.cross(size) / columns as f32;
+        let offset = list.offset(scrollable, list.content_bounds(heights, bounds), bounds);
+        let (start, end) = get_visible_range(
+            heights.len(),
+            offset,
+            list.row_height,
+            axis.main(size),
+            list.overscan,
+        );
+        let window = Window {
+            start,
+            end,
+            offset,
+            size,
+        };
+
+        if self.window == Some(window) {
+            return;
+        }
+
+        self.realize(list.items(start..end, columns), list);
+
+        let limits = layout::Limits::new(axis.size(0.0, cell), axis.size(list.row_height, cell));
+        let nodes = self.layout_rows(renderer, |_| limits);
+        let rows = self.place(axis, nodes, columns, cell, |row| {
+            list.row_position(
+                row as f32 * list.row_height - offset,
+                list.row_height,
+                axis.main(size),
+            )
+        });
+
+        self.finish_layout(axis, window, heights.total(), rows);
+    }
+
+    /// Lays out the rows of a variable height [`FlatList`], recording their
+    /// heights as they get realized.
+    fn measure(
+        &mut self,
+        scrollable: &mut new_scrollable::State,
+        heights: &mut RowHeights,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        list: &FlatList<'a, Message, Renderer>,
+    ) {
+        let size = bounds.size();
+        let axis = list.axis;
+        let columns = list.column_count(axis.cross(size));
+        let cell = axis.cross(size) / columns as f32;
+        let limits = layout::Limits::new(axis.size(0.0, cell), axis.size(f32::INFINITY, cell));
+
+        // Measuring rows moves the rows after them, so we keep laying out
+        // until the measured heights stop changing the visible range.
+        for pass in 1..=MAX_MEASURE_PASSES {
+            let mut offset = list.offset(scrollable, list.content_bounds(heights, bounds), bounds);
+            let window = visible_window(heights, offset, axis.main(size), size, list.overscan);
+
+            if self.window == Some(window) {
+                return;
+            }
+
+            self.realize(list.items(window.start..window.end, columns), list);
+
+            // Placeholders stand in for rows that were never measured, so
+            // they take the height the rows are expected to have.
+            let nodes = self.layout_rows(renderer, |item| {
+                if list.is_placeholder(item) {
+                    let length = axis.size(heights.height(item / columns), cell);
+
+                    layout::Limits::new(length, length)
+                } else {
+                    limits
+                }
+            });
+
+            for (row, cells) in (window.start..window.end).zip(nodes.chunks(columns)) {
+                let height = cells
+                    .iter()
+                    .zip(row * columns..)
+                    .filter(|(_, item)| !list.is_placeholder(*item))
+                    .map(|(cell, _)| axis.main(cell.size()))
+                    .filter(|height| height.is_finite())
+                    .reduce(f32::max);
+
+                if let Some(height) = height {
+                    let top = heights.offset_of(row);
+                    let delta = heights.set(row, height);
+
+                    // Rows starting above the viewport grow upwards, so the
+                    // visible rows stay where they are.
+                    if top < offset {
+                        offset += delta;
+                    }
+                }
+            }
+
+            list.scroll_to(scrollable, heights, bounds, offset);
+
+            let offset = list.offset(scrollable, list.content_bounds(heights, bounds), bounds);
+            let settled = visible_window(heights, offset, axis.main(size), size, list.overscan);
+
+            if (settled.start, settled.end) == (window.start, window.end)
+                || pass == MAX_MEASURE_PASSES
+            {
+                let rows = self.place(axis, nodes, columns, cell, |row| {
+                    list.row_position(
+                        heights.offset_of(row) - offset,
+                        heights.height(row),
+                        axis.main(size),
+                    )
+                });
+
+                self.finish_layout(
+                    axis,
+                    Window {
+                        start: window.start,
+                        end: window.end,
+                        ..settled
+                    },
+                    heights.total(),
+                    rows,
+                );
+
+                return;
+            }
+        }
+    }
+
+    /// Pins the header of the section at the top of the viewport, pushed away
+    /// by the header of the next section.
+    fn stick(
+        &mut self,
+        tree: &mut Tree,
+        heights: &RowHeights,
+        renderer: &Renderer,
+        list: &FlatList<'a, Message, Renderer>,
+    ) {
+        let header = list
+            .sections
+            .as_ref()
+            .filter(|_| !list.inverted)
+            .zip(self.window)
+            .and_then(|(sections, window)| {
+                let section = sections.section_of(heights.index_at(window.offset))?;
+                let index = sections.header_index(section);
+
+                // A header that is still in place does not need to be pinned.
+                if heights.offset_of(index) >= window.offset {
+                    return None;
+                }
+
+                let length = heights.height(index);
+                let position = (section + 1 < sections.section_count())
+                    .then(|| heights.offset_of(sections.header_index(section + 1)) - window.offset)
+                    .map_or(0.0, |next| (next - length).min(0.0));
+
+                Some((index, length, position, window.size))
+            });
+
+        let Some((index, length, position, size)) = header else {
+            self.sticky = None;
+            return;
+        };
+
+        let axis = list.axis;
+        let element = match self.sticky.take() {
+            Some(sticky) if sticky.index == index => sticky.element,
+            _ => {
+                let element = list.row(index);
+                tree.diff(&element);
+                element
+            }
+        };
+
+        let cross = axis.cross(size);
+        let limits = layout::Limits::new(axis.size(length, cross), axis.size(length, cross));
+        let mut layout = element.as_widget().layout(renderer, &limits);
+        layout.move_to(axis.point(position, 0.0));
+
+        self.sticky = Some(Sticky {
+            index,
+            element,
+            layout,
+        });
+    }
+
+    /// Lays out the realized items, given the limits of each item.
+    fn layout_rows(
+        &self,
+        renderer: &Renderer,
+        limits: impl Fn(usize) -> layout::Limits,
+    ) -> Vec<layout::Node> {
+        self.rows
+            .iter()
+            .zip(self.realized.clone())
+            .map(|(row, item)| row.as_widget().layout(renderer, &limits(item)))
+            .collect()
+    }
+
+    /// Moves the nodes of the realized items to their cell, given the
+    /// position of each row along the [`Axis`].
+    fn place(
+        &self,
+        axis: Axis,
+        nodes: Vec<layout::Node>,
+        columns: usize,
+        cell: f32,
+        row_position: impl Fn(usize) -> f32,
+    ) -> Vec<layout::Node> {
+        nodes
+            .into_iter()
+            .zip(self.realized.clone())
+            .map(|(mut node, item)| {
+                node.move_to(
+                    axis.point(row_position(item / columns), (item % columns) as f32 * cell),
+                );
+                node
+            })
+            .collect()
+    }
+
+    /// Builds the items in `range`, unless they are already realized.
+    fn realize(&mut self, range: Range<usize>, list: &FlatList<'a, Message, Renderer>) {
+        if self.realized == range {
+            return;
+        }
+
+        self.rows = range.clone().map(|index| list.row(index)).collect();
+        self.keys = row_keys(range.clone().map(|index| list.item_key(index)));
+        self.realized = range;
+        self.is_diffed = false;
+    }
+
+    /// Hands the widget tree of every previously realized item to the row of
+    /// the same item, so the state of a row follows its item as it scrolls.
+    ///
+    /// `keys` holds the keys of the items that the children of `tree` belong
+    /// to.
+    fn diff(&mut self, tree: &mut Tree, keys: &mut Vec<u64>) {
+        if self.is_diffed {
+            return;
+        }
+
+        let mut previous: HashMap<u64, Tree> =
+            keys.drain(..).zip(tree.children.drain(..)).collect();
+
+        tree.children = self
+            .rows
+            .iter()
+            .zip(&self.keys)
+            .map(|(row, key)| match previous.remove(key) {
+                Some(mut tree) => {
+                    tree.diff(row);
+                    tree
+                }
+                None => Tree::new(row),
+            })
+            .collect();
+
+        keys.clone_from(&self.keys);
+        self.is_diffed = true;
+    }
+
+    fn finish_layout(
+        &mut self,
+        axis: Axis,
+        window: Window,
+        total_height: f32,
+        rows: Vec<layout::Node>,
+    ) {
+        self.layout = layout::Node::with_children(
+            window.size,
+            vec![layout::Node::with_children(
+                axis.size(total_height, axis.cross(window.size)),
+                rows,
+            )],
+        );
+        self.window = Some(window);
+    }
+}
+
+/// The maximum amount of times a variable height [`FlatList`] lays out its
+/// rows while looking for a stable window.
+const MAX_MEASURE_PASSES: usize = 4;
+
+/// The default [`Overscan`] of a [`FlatList`], in rows.
+const DEFAULT_OVERSCAN: usize = 2;
+
+/// Computes the [`Window`] of rows intersecting a viewport `length` pixels
+/// long scrolled by `offset`, extended by the [`Overscan`].
+fn visible_window(
+    heights: &RowHeights,
+    offset: f32,
+    length: f32,
+    size: Size,
+    overscan: Overscan,
+) -> Window {
+    let first = heights.index_at(offset);
+    let mut end = first;
+    let mut top = heights.offset_of(first);
+
+    while end < heights.len() && top < offset + length {
+        top += heights.height(end);
+        end += 1;
+    }
+
+    let (start, end) = overscan.apply(first, end, heights.len());
+
+    Window {
+        start,
+        end,
+        offset,
+        size,
+    }
+}
+
+/// Returns the keys identifying the widget state of the rows of items with
+/// the given `keys`.
+///
+/// Items with the same key get a different row key for every time the key
+/// occurred before, so their rows do not share their widget state.
+fn row_keys(keys: impl Iterator<Item = u64>) -> Vec<u64> {
+    let mut occurrences: HashMap<u64, u64> = HashMap::new();
+
+    keys.map(|key| {
+        let occurrence = occurrences.entry(key).or_default();
+        let row_key = match *occurrence {
+            0 => key,
+            occurrence => {
+                let mut hasher = Hasher::default();
+                (key, occurrence).hash(&mut hasher);
+                hasher.finish()
+            }
+        };
+
+        *occurrence += 1;
+        row_key
+    })
+    .collect()
+}
+
+struct State {
+    tree: RefCell<Tree>,
+    keys: RefCell<Vec<u64>>,
+    scrollable: RefCell<new_scrollable::State>,
+    heights: RefCell<RowHeights>,
+    columns: Cell<usize>,
+    sticky: RefCell<Tree>,
+    last_viewport: Option<ListViewport>,
+    start_reached_at: Option<usize>,
+    end_reached_at: Option<usize>,
+    requested: Option<Range<usize>>,
+    anchor: Cell<Option<Anchor>>,
+    focus: Focus,
+    selected: HashSet<u64>,
+    selection_anchor: Option<usize>,
+    drag: Option<Drag>,
+    drag_tree: Tree,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for FlatList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet + selection::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            tree: RefCell::new(Tree::empty()),
+            keys: RefCell::new(Vec::new()),
+            scrollable: RefCell::new(self.scrollable()),
+            heights: RefCell::new(RowHeights::default()),
+            columns: Cell::new(0),
+            sticky: RefCell::new(Tree::empty()),
+            last_viewport: None,
+            start_reached_at: None,
+            end_reached_at: None,
+            requested: None,
+            anchor: Cell::new(None),
+            focus: Focus::default(),
+            selected: HashSet::new(),
+            selection_anchor: None,
+            drag: None,
+            drag_tree: Tree::empty(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.header.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let scrollable = tree.state.downcast_mut::<State>().scrollable.get_mut();
+
+        if self.stick_to_end && !scrollable.sticks_to_end() {
+            scrollable.stick_to_end();
+        }
+
+        scrollable.set_kinetic(self.kinetic);
+        scrollable.set_smooth(self.smooth);
+        scrollable.set_wheel(self.scroll_wheel());
+
+        tree.diff_children(self.header.as_slice());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let header = self
+            .header
+            .as_ref()
+            .map(|header| header.as_widget().layout(renderer, &limits.loose()));
+        let header_height = header.as_ref().map_or(0.0, |header| header.size().height);
+
+        let max = limits.max();
+        let cross = self
+            .axis
+            .cross(Size::new(max.width, (max.height - header_height).max(0.0)));
+        let rows = self.row_count(self.column_count(cross));
+        let list = self.axis.size(rows as f32 * self.row_height, cross);
+
+        let size = limits.resolve(Size::new(list.width, list.height + header_height));
+        let mut viewport = layout::Node::new(Size::new(
+            size.width,
+            (size.height - header_height).max(0.0),
+        ));
+        viewport.move_to(Point::new(0.0, header_height));
+
+        layout::Node::with_children(size, header.into_iter().chain(Some(viewport)).collect())
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let (header_layout, layout) = self.split(layout);
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        operation.scrollable(state.scrollable.get_mut(), self.id.as_ref().map(|id| &id.0));
+        operation.focusable(&mut state.focus, self.id.as_ref().map(|id| &id.0));
+
+        let mut request = Request::default();
+        operation.custom(&mut request, self.id.as_ref().map(|id| &id.0));
+
+        if let Some((index, align)) = request.scroll_to {
+            // The rows need to be grouped for the current bounds first.
+            drop(self.content(state, renderer, layout.bounds()));
+            self.reveal(state, layout.bounds(), index, align, request.is_animated);
+        }
+
+        operation.custom(state.scrollable.get_mut(), self.id.as_ref().map(|id| &id.0));
+
+        let mut content = self.content(state, renderer, layout.bounds());
+        operation.custom(
+            &mut Viewport::new(
+                state.scrollable.get_mut(),
+                layout.bounds(),
+                self.content_bounds(state.heights.get_mut(), layout.bounds()),
+            ),
+            self.id.as_ref().map(|id| &id.0),
+        );
+
+        let Content {
+            layout: node, rows, ..
+        } = &mut *content;
+        let tree = state.tree.get_mut();
+        let layout = Layout::with_offset(layout.position() - Point::ORIGIN, node);
+
+        operation.container(self.id.as_ref().map(|id| &id.0), &mut |operation| {
+            if let (Some(header), Some(layout)) = (&self.header, header_layout) {
+                header
+                    .as_widget()
+                    .operate(&mut children[0], layout, renderer, operation);
+            }
+
+            let content_layout = layout.children().next().unwrap();
+
+            rows.iter()
+                .zip(&mut tree.children)
+                .zip(content_layout.children())
+                .for_each(|((row, tree), layout)| {
+                    row.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let (header_layout, layout) = self.split(layout);
+
+        if let (Some(header), Some(layout)) = (&mut self.header, header_layout) {
+            let status = header.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let mut content = self.content(state, renderer, bounds);
+        let Content {
+            layout: node,
+            rows,
+            sticky,
+            ..
+        } = &mut *content;
+        let State {
+            tree,
+            scrollable,
+            sticky: sticky_tree,
+            ..
+        } = &mut *state;
+        let tree = tree.get_mut();
+        let sticky_tree = sticky_tree.get_mut();
+
+        // A horizontal list has nothing to scroll vertically, so the lines of
+        // a regular mouse wheel move it sideways instead.
+        let event = match (self.axis, event) {
+            (
+                Axis::Horizontal,
+                Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x, y },
+                }),
+            ) => Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: y, y: x },
+            }),
+            (_, event) => event,
+        };
+        let input = event.clone();
+        let is_over_sticky = sticky
+            .as_ref()
+            .is_some_and(|sticky| sticky.contains(bounds, cursor_position));
+
+        let status = new_scrollable::update(
+            scrollable.get_mut(),
+            event,
+            Layout::with_offset(layout.position() - Point::ORIGIN, node),
+            cursor_position,
+            clipboard,
+            shell,
+            &self.vertical,
+            self.horizontal(),
+            &None,
+            |event, layout, mut cursor_position, clipboard, shell| {
+                if let Some(sticky) = sticky {
+                    let sticky_cursor_position = sticky.cursor_position(bounds, cursor_position);
+                    let status = sticky.element.as_widget_mut().on_event(
+                        sticky_tree,
+                        event.clone(),
+                        Layout::with_offset(bounds.position() - Point::ORIGIN, &sticky.layout),
+                        sticky_cursor_position,
+                        renderer,
+                        clipboard,
+                        shell,
+                    );
+
+                    if status == event::Status::Captured {
+                        return status;
+                    }
+
+                    // The pinned header hides the rows scrolling under it.
+                    if sticky.contains(bounds, cursor_position) {
+                        cursor_position = Point::new(-1.0, -1.0);
+                    }
+                }
+
+                rows.iter_mut()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                    .map(|((row, tree), layout)| {
+                        row.as_widget_mut().on_event(
+                            tree,
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        let auto_scroll = self
+            .auto_scroll
+            .or_else(|| self.on_reorder.as_ref().map(|_| AutoScroll::default()));
+
+        if let Some(auto_scroll) = auto_scroll {
+            new_scrollable::auto_scroll(
+                scrollable.get_mut(),
+                &input,
+                Layout::with_offset(layout.position() - Point::ORIGIN, node),
+                cursor_position,
+                &auto_scroll,
+                &None,
+                shell,
+            );
+        }
+
+        let status = match input {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let item = if is_over_sticky {
+                    None
+                } else {
+                    self.item_at(state, bounds, cursor_position)
+                };
+
+                self.click(state, bounds, cursor_position, item, status, shell);
+
+                status
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.drag.is_some() => {
+                status.merge(self.drag(state, bounds, position))
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.is_some() =>
+            {
+                status.merge(self.release(state, shell))
+            }
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                // The list may have scrolled under the dragged row.
+                if let Some(drag) = state.drag.filter(|drag| drag.is_dragging) {
+                    let gap = self.gap_at(state, bounds, drag.cursor_position);
+
+                    state.drag = Some(Drag { gap, ..drag });
+                }
+
+                status
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if status == event::Status::Ignored && state.focus.is_focused => {
+                self.key_press(state, bounds, key_code, modifiers, shell)
+            }
+            _ => status,
+        };
+
+        self.notify_on_viewport_change(state, bounds, shell);
+        self.notify_on_edges(state, bounds, shell);
+        self.request_missing(state, content.realized.clone(), shell);
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let (header_layout, layout) = self.split(layout);
+
+        if let (Some(header), Some(layout)) = (&self.header, header_layout) {
+            header.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let content = self.content(state, renderer, bounds);
+        let tree = state.tree.borrow();
+
+        new_scrollable::draw(
+            &state.scrollable.borrow(),
+            renderer,
+            theme,
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            &self.vertical,
+            self.horizontal(),
+            &self.style,
+            |renderer, layout, cursor_position, viewport| {
+                let selected = (!state.selected.is_empty()).then(|| {
+                    <Renderer::Theme as selection::StyleSheet>::selected(
+                        theme,
+                        &self.selection_style,
+                    )
+                });
+
+                content
+                    .rows
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .zip(content.realized.clone())
+                    .for_each(|(((row, tree), layout), index)| {
+                        if let Some(appearance) =
+                            selected.filter(|_| self.is_selected(state, index))
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: layout.bounds(),
+                                    border_radius: appearance.border_radius.into(),
+                                    border_width: appearance.border_width,
+                                    border_color: appearance.border_color,
+                                },
+                                appearance.background,
+                            );
+                        }
+
+                        row.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        )
+                    });
+
+                if let Some(indicator) =
+                    state.drag.filter(|drag| drag.is_dragging).and_then(|drag| {
+                        self.drop_indicator(
+                            drag.gap,
+                            &content.realized,
+                            state.columns.get().max(1),
+                            layout,
+                        )
+                    })
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: indicator,
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        <Renderer::Theme as selection::StyleSheet>::drop_indicator(
+                            theme,
+                            &self.selection_style,
+                        ),
+                    );
+                }
+
+                if let Some(sticky) = &content.sticky {
+                    sticky.element.as_widget().draw(
+                        &state.sticky.borrow(),
+                        renderer,
+                        theme,
+                        style,
+                        sticky.layout(bounds),
+                        sticky.cursor_position(bounds, cursor_position),
+                        viewport,
+                    );
+                }
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (header_layout, layout) = self.split(layout);
+
+        if let (Some(header), Some(layout)) = (&self.header, header_layout) {
+            if layout.bounds().contains(cursor_position) {
+                return header.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                );
+            }
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bounds = layout.bounds();
+        let content = self.content(state, renderer, bounds);
+        let tree = state.tree.borrow();
+
+        new_scrollable::mouse_interaction(
+            &state.scrollable.borrow(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            &self.vertical,
+            self.horizontal(),
+            |layout, cursor_position, viewport| {
+                if let Some(sticky) = &content.sticky {
+                    if sticky.contains(bounds, cursor_position) {
+                        return sticky.element.as_widget().mouse_interaction(
+                            &state.sticky.borrow(),
+                            sticky.layout(bounds),
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        );
+                    }
+                }
+
+                content
+                    .rows
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((row, tree), layout)| {
+                        row.as_widget().mouse_interaction(
+                            tree,
+                            layout,
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (header_layout, layout) = self.split(layout);
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        drop(self.content(state, renderer, layout.bounds()));
+
+        let dragged = state
+            .drag
+            .filter(|drag| drag.is_dragging && drag.index < self.item_count)
+            .map(|drag| (drag, self.row(drag.index)));
+
+        let Self {
+            header,
+            content,
+            selection_style,
+            axis,
+            ..
+        } = self;
+        let Content {
+            layout: node, rows, ..
+        } = content.get_mut();
+        let State {
+            tree: rows_tree,
+            drag_tree,
+            ..
+        } = state;
+        let layout = Layout::with_offset(layout.position() - Point::ORIGIN, node);
+
+        let header = header
+            .as_mut()
+            .zip(header_layout)
+            .and_then(|(header, layout)| {
+                header
+                    .as_widget_mut()
+                    .overlay(&mut children[0], layout, renderer)
+            });
+        let rows = overlay::from_children(
+            rows,
+            rows_tree.get_mut(),
+            layout.children().next().unwrap(),
+            renderer,
+        );
+        let dragged = dragged.map(|(drag, row)| {
+            drag_tree.diff(&row);
+
+            overlay::Element::new(
+                drag.position(*axis),
+                Box::new(Dragged {
+                    row,
+                    tree: drag_tree,
+                    size: drag.bounds.size(),
+                    style: selection_style,
+                }),
+            )
+        });
+
+        let mut overlays: Vec<_> = [header, rows, dragged].into_iter().flatten().collect();
+
+        match overlays.len() {
+            0 | 1 => overlays.pop(),
+            _ => Some(overlay::Group::with_children(overlays).overlay()),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<FlatList<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet + selection::StyleSheet,
+{
+    fn from(flat_list: FlatList<'a, Message, Renderer>) -> Self {
+        Self::new(flat_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_scrollable, row_keys, FlatList, State};
+
+    use std::collections::HashSet;
+
+    use std::ops::Range;
+
+    use iced_native::clipboard;
+    use iced_native::event::{self, Event};
+    use iced_native::keyboard::KeyCode;
+    use iced_native::layout::{self, Layout};
+    use iced_native::mouse;
+    use iced_native::widget::{Space, Tree};
+    use iced_native::{Length, Point, Rectangle, Shell, Size, Widget};
+
+    use crate::data_source::{DataSource, MemorySource, Page};
+    use crate::new_scrollable::Wheel;
+    use crate::row_heights::RowHeights;
+
+    type Renderer = iced::Renderer;
+
+    /// A viewport that fits five rows of 20 pixels.
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn rows(len: usize) -> FlatList<'static, (), Renderer> {
+        FlatList::new(len, 20.0, |_| Space::with_width(Length::Fill).into())
+    }
+
+    /// Creates the state of the `list`, with its rows laid out at their
+    /// estimated height.
+    fn tree(list: &FlatList<'static, (), Renderer>) -> Tree {
+        let tree = Tree::new(list as &dyn Widget<_, Renderer>);
+        let state = tree.state.downcast_ref::<State>();
+
+        *state.heights.borrow_mut() = RowHeights::new(list.item_count, 20.0);
+        state.columns.set(1);
+
+        tree
+    }
+
+    #[test]
+    fn viewport_shows_the_visible_items() {
+        let list = rows(100);
+        let tree = tree(&list);
+
+        assert_eq!(
+            list.viewport(tree.state.downcast_ref(), BOUNDS).visible,
+            0..5
+        );
+    }
+
+    #[test]
+    fn viewport_of_an_inverted_list_starts_at_the_first_item() {
+        let list = rows(100).inverted();
+        let tree = tree(&list);
+
+        assert_eq!(
+            list.viewport(tree.state.downcast_ref(), BOUNDS).visible,
+            0..5
+        );
+    }
+
+    #[test]
+    fn navigate_focuses_the_first_visible_item() {
+        let list = rows(100);
+        let mut tree = tree(&list);
+        let state = tree.state.downcast_mut::<State>();
+
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::Down), Some(0));
+
+        state.focus.index = Some(0);
+
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::Down), Some(1));
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::PageDown), Some(5));
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::End), Some(99));
+    }
+
+    #[test]
+    fn navigate_an_inverted_list_from_the_first_visible_item() {
+        let list = rows(100).inverted();
+        let mut tree = tree(&list);
+        let state = tree.state.downcast_mut::<State>();
+
+        for key_code in [KeyCode::Up, KeyCode::Down, KeyCode::PageUp] {
+            assert_eq!(list.navigate(state, BOUNDS, key_code), Some(0));
+        }
+
+        state.focus.index = Some(0);
+
+        // The items of an inverted list run upwards.
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::Up), Some(1));
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::Down), Some(0));
+        assert_eq!(list.navigate(state, BOUNDS, KeyCode::PageUp), Some(5));
+    }
+
+    #[test]
+    fn item_at_finds_the_row_under_the_point() {
+        let list = rows(100);
+        let tree = tree(&list);
+        let state = tree.state.downcast_ref::<State>();
+
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 5.0)), Some(0));
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 45.0)), Some(2));
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 150.0)), None);
+    }
+
+    #[test]
+    fn item_at_ignores_the_space_past_the_last_row() {
+        let list = rows(2);
+        let tree = tree(&list);
+        let state = tree.state.downcast_ref::<State>();
+
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 25.0)), Some(1));
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 90.0)), None);
+    }
+
+    #[test]
+    fn item_at_ignores_the_space_before_the_first_row_of_an_inverted_list() {
+        let list = rows(2).inverted();
+        let tree = tree(&list);
+        let state = tree.state.downcast_ref::<State>();
+
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 90.0)), Some(0));
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 75.0)), Some(1));
+        assert_eq!(list.item_at(state, BOUNDS, Point::new(50.0, 5.0)), None);
+    }
+
+    /// Scrolls the viewport of a `list` of 100 rows, 90 pixels long, by a
+    /// `line` of the mouse wheel, and returns the resulting offset of the list.
+    fn scroll_line(list: &FlatList<'static, (), Renderer>, line: f32) -> f32 {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 90.0));
+        let content_bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 2000.0));
+        let node = layout::Node::with_children(
+            bounds.size(),
+            vec![layout::Node::new(content_bounds.size())],
+        );
+        let mut scrollable = list.scrollable();
+        let mut messages: Vec<()> = Vec::new();
+
+        new_scrollable::update(
+            &mut scrollable,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: line },
+            }),
+            Layout::new(&node),
+            Point::new(50.0, 45.0),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &list.vertical,
+            None,
+            &None,
+            |_, _, _, _, _| event::Status::Ignored,
+        );
+
+        list.offset(&scrollable, content_bounds, bounds)
+    }
+
+    #[test]
+    fn wheel_scrolls_by_whole_rows() {
+        let list = rows(100).wheel(Wheel::new().rows(1, 20.0));
+
+        assert_eq!(scroll_line(&list, -1.0), 20.0);
+        assert_eq!(scroll_line(&list, -2.0), 40.0);
+    }
+
+    #[test]
+    fn wheel_scrolls_an_inverted_list_by_whole_rows() {
+        let list = rows(100).inverted().wheel(Wheel::new().rows(1, 20.0));
+
+        assert_eq!(scroll_line(&list, 1.0), 20.0);
+        assert_eq!(scroll_line(&list, 2.0), 40.0);
+    }
+
+    #[test]
+    fn row_keys_tell_repeated_keys_apart() {
+        assert_eq!(row_keys([3, 1, 2].into_iter()), [3, 1, 2]);
+
+        let keys = row_keys([7, 1, 7, 7, 1].into_iter());
+
+        assert_eq!(keys[..2], [7, 1]);
+        assert_eq!(keys.iter().collect::<HashSet<_>>().len(), 5);
+        assert_eq!(keys, row_keys([7, 1, 7, 7, 1].into_iter()));
+    }
+
+    fn source(len: usize) -> MemorySource<usize, Range<usize>> {
+        MemorySource::new((0..len).collect(), 10, |page: Page<usize>| {
+            page.start..page.start + page.items.len()
+        })
+    }
+
+    fn list(source: &MemorySource<usize, Range<usize>>) -> FlatList<'_, Range<usize>, Renderer> {
+        FlatList::with_source(
+            source,
+            20.0,
+            |_| Space::with_width(Length::Fill).into(),
+            |range| range,
+        )
+    }
+
+    #[test]
+    fn request_missing_publishes_each_range_once() {
+        let mut source = source(100);
+        let mut messages = Vec::new();
+
+        {
+            let list = list(&source);
+            let mut tree = Tree::new(&list as &dyn Widget<_, Renderer>);
+            let state = tree.state.downcast_mut::<State>();
+            let mut shell = Shell::new(&mut messages);
+
+            list.request_missing(state, 0..12, &mut shell);
+            list.request_missing(state, 0..12, &mut shell);
+            list.request_missing(state, 3..15, &mut shell);
+            list.request_missing(state, 3..15, &mut shell);
+        }
+
+        assert_eq!(messages, [0..12, 3..15]);
+
+        let _ = source.request(0..10);
+        source.load(Page {
+            start: 0,
+            items: (0..10).collect(),
+        });
+        messages.clear();
+
+        let list = list(&source);
+        let mut tree = Tree::new(&list as &dyn Widget<_, Renderer>);
+        let state = tree.state.downcast_mut::<State>();
+        let mut shell = Shell::new(&mut messages);
+
+        // Loaded items are never asked for again.
+        list.request_missing(state, 0..10, &mut shell);
+        list.request_missing(state, 5..14, &mut shell);
+        list.request_missing(state, 5..14, &mut shell);
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0], 10..14);
+    }
+}
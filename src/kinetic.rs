@@ -0,0 +1,345 @@
+//! Keep scrolling after a fling.
+use iced_native::time::{Duration, Instant};
+use iced_native::Vector;
+
+/// How the content of a scrollable keeps moving after a fling.
+///
+/// Once the finger is lifted, the content keeps the velocity it was moved
+/// with and slows down exponentially until it stops.
+#[derive(Debug, Clone, Copy)]
+pub struct Kinetic {
+    decay: f32,
+    min_speed: f32,
+    max_speed: f32,
+    clock: fn() -> Instant,
+}
+
+impl Default for Kinetic {
+    fn default() -> Self {
+        Self {
+            decay: 3.0,
+            min_speed: 20.0,
+            max_speed: 8000.0,
+            clock: Instant::now,
+        }
+    }
+}
+
+impl Kinetic {
+    /// The time after which a finger resting on the content loses its
+    /// velocity.
+    const REST: Duration = Duration::from_millis(100);
+
+    /// Creates a new [`Kinetic`] for use in a scrollable.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rate at which a fling slows down, per second.
+    ///
+    /// After one second, the velocity is multiplied by `e^-decay`.
+    pub fn decay(mut self, decay: f32) -> Self {
+        self.decay = decay.max(f32::EPSILON);
+        self
+    }
+
+    /// Sets the speed, in pixels per second, under which a fling stops.
+    pub fn min_speed(mut self, min_speed: f32) -> Self {
+        self.min_speed = min_speed.max(0.0);
+        self
+    }
+
+    /// Sets the speed, in pixels per second, that a fling never exceeds.
+    pub fn max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = max_speed.max(0.0);
+        self
+    }
+
+    /// Sets the clock telling the time of the touch events, which do not
+    /// carry their own.
+    ///
+    /// It is [`Instant::now`] by default, and can be replaced to drive a
+    /// scrollable with synthetic events.
+    pub fn clock(mut self, clock: fn() -> Instant) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the current time, as told by the clock of the [`Kinetic`].
+    pub fn now(&self) -> Instant {
+        (self.clock)()
+    }
+}
+
+/// The velocity of a scrollable moved by a finger, and of the fling that
+/// follows.
+///
+/// Every method takes the time it happens at, so a [`Momentum`] can be driven
+/// by any clock.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Momentum {
+    velocity: Vector,
+    tracked_at: Option<Instant>,
+    advanced_at: Option<Instant>,
+}
+
+impl Momentum {
+    /// Creates a new [`Momentum`] at rest.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the velocity of the [`Momentum`], in pixels per second.
+    pub fn velocity(&self) -> Vector {
+        self.velocity
+    }
+
+    /// Returns `true` if the content is moving after a fling.
+    pub fn is_flinging(&self) -> bool {
+        self.advanced_at.is_some()
+    }
+
+    /// Starts tracking a finger pressed at `now`, stopping any fling.
+    pub fn press(&mut self, now: Instant) {
+        *self = Self {
+            tracked_at: Some(now),
+            ..Self::default()
+        };
+    }
+
+    /// Tracks the finger moving the content by `delta` at `now`.
+    pub fn track(&mut self, delta: Vector, now: Instant) {
+        if let Some(tracked_at) = self.tracked_at {
+            let elapsed = now.saturating_duration_since(tracked_at).as_secs_f32();
+
+            // Smooth the velocity, as touch events come in irregularly.
+            if elapsed > 0.0 {
+                self.velocity = delta * (0.8 / elapsed) + self.velocity * 0.2;
+            }
+        }
+
+        self.tracked_at = Some(now);
+    }
+
+    /// Releases the finger at `now`, starting a fling if it was moving fast
+    /// enough.
+    ///
+    /// Returns `true` if the content is flinging.
+    pub fn release(&mut self, now: Instant, kinetic: &Kinetic) -> bool {
+        let is_resting = self
+            .tracked_at
+            .is_none_or(|tracked_at| now.saturating_duration_since(tracked_at) > Kinetic::REST);
+
+        let speed = length(self.velocity);
+
+        if speed > kinetic.max_speed {
+            self.velocity = self.velocity * (kinetic.max_speed / speed);
+        }
+
+        if is_resting || speed < kinetic.min_speed {
+            self.stop();
+        } else {
+            self.tracked_at = None;
+            self.advanced_at = Some(now);
+        }
+
+        self.is_flinging()
+    }
+
+    /// Advances the fling to `now`, slowing it down.
+    ///
+    /// Returns how far the content moved since the last advance, if it is
+    /// flinging.
+    pub fn advance(&mut self, now: Instant, kinetic: &Kinetic) -> Option<Vector> {
+        let advanced_at = self.advanced_at?;
+        let elapsed = now.saturating_duration_since(advanced_at).as_secs_f32();
+        let factor = (-kinetic.decay * elapsed).exp();
+
+        // The distance covered by a velocity that decays exponentially.
+        let delta = self.velocity * ((1.0 - factor) / kinetic.decay);
+
+        self.velocity = self.velocity * factor;
+        self.advanced_at = Some(now);
+
+        if length(self.velocity) < kinetic.min_speed {
+            self.stop();
+        }
+
+        Some(delta)
+    }
+
+    /// Advances the fling to `now` and moves the content by how far it went
+    /// with `scroll`, which returns `false` if the content could not move.
+    ///
+    /// Returns `true` if the content was flinging.
+    pub fn fling(
+        &mut self,
+        now: Instant,
+        kinetic: &Kinetic,
+        scroll: impl FnOnce(Vector) -> bool,
+    ) -> bool {
+        let Some(delta) = self.advance(now, kinetic) else {
+            return false;
+        };
+
+        // Stop at the edges instead of pushing against them.
+        if !scroll(delta) {
+            self.stop();
+        }
+
+        true
+    }
+
+    /// Stops the [`Momentum`] dead.
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+}
+
+fn length(vector: Vector) -> f32 {
+    vector.x.hypot(vector.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kinetic, Momentum};
+
+    use iced_native::time::{Duration, Instant};
+    use iced_native::Vector;
+
+    const FRAME: Duration = Duration::from_millis(10);
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    /// Presses a finger at `start` and moves it `step` pixels down every
+    /// frame, `frames` times. Returns when the finger last moved.
+    fn swipe(momentum: &mut Momentum, start: Instant, step: f32, frames: u32) -> Instant {
+        momentum.press(start);
+
+        (1..=frames).fold(start, |_, frame| {
+            let now = start + FRAME * frame;
+            momentum.track(Vector::new(0.0, step), now);
+            now
+        })
+    }
+
+    #[test]
+    fn press_starts_at_rest() {
+        let mut momentum = Momentum::new();
+        swipe(&mut momentum, Instant::now(), 10.0, 5);
+
+        momentum.press(Instant::now());
+
+        assert_eq!(momentum.velocity(), Vector::new(0.0, 0.0));
+        assert!(!momentum.is_flinging());
+    }
+
+    #[test]
+    fn track_smooths_the_velocity() {
+        let mut momentum = Momentum::new();
+        let start = Instant::now();
+
+        swipe(&mut momentum, start, 10.0, 1);
+        assert_near(momentum.velocity().y, 800.0);
+
+        swipe(&mut momentum, start, 10.0, 20);
+        assert_near(momentum.velocity().y, 1000.0);
+        assert_near(momentum.velocity().x, 0.0);
+    }
+
+    #[test]
+    fn release_flings_a_fast_finger() {
+        let kinetic = Kinetic::new();
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+
+        assert!(momentum.release(now, &kinetic));
+        assert!(momentum.is_flinging());
+    }
+
+    #[test]
+    fn release_stops_a_resting_finger() {
+        let kinetic = Kinetic::new();
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+
+        assert!(!momentum.release(now + Duration::from_millis(200), &kinetic));
+        assert_eq!(momentum.velocity(), Vector::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn release_stops_a_slow_finger() {
+        let kinetic = Kinetic::new().min_speed(200.0);
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 1.0, 20);
+
+        assert!(!momentum.release(now, &kinetic));
+        assert!(!momentum.is_flinging());
+    }
+
+    #[test]
+    fn release_caps_the_speed() {
+        let kinetic = Kinetic::new().max_speed(500.0);
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+
+        assert!(momentum.release(now, &kinetic));
+        assert_near(momentum.velocity().y, 500.0);
+    }
+
+    #[test]
+    fn advance_slows_down_exponentially() {
+        let kinetic = Kinetic::new().decay(2.0).min_speed(1.0);
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+        let velocity = momentum.velocity().y;
+
+        momentum.release(now, &kinetic);
+
+        let delta = momentum.advance(now + Duration::from_secs(1), &kinetic);
+        let factor = (-2.0f32).exp();
+
+        assert_near(delta.unwrap().y, velocity * (1.0 - factor) / 2.0);
+        assert_near(momentum.velocity().y, velocity * factor);
+        assert!(momentum.is_flinging());
+    }
+
+    #[test]
+    fn advance_stops_under_the_minimum_speed() {
+        let kinetic = Kinetic::new();
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+
+        momentum.release(now, &kinetic);
+
+        assert!(momentum
+            .advance(now + Duration::from_secs(5), &kinetic)
+            .is_some());
+        assert!(!momentum.is_flinging());
+        assert_eq!(
+            momentum.advance(now + Duration::from_secs(6), &kinetic),
+            None
+        );
+    }
+
+    #[test]
+    fn fling_stops_at_the_edges() {
+        let kinetic = Kinetic::new();
+        let mut momentum = Momentum::new();
+        let now = swipe(&mut momentum, Instant::now(), 10.0, 20);
+
+        momentum.release(now, &kinetic);
+
+        assert!(momentum.fling(now + FRAME, &kinetic, |delta| delta.y > 0.0));
+        assert!(momentum.is_flinging());
+
+        assert!(momentum.fling(now + FRAME * 2, &kinetic, |_| false));
+        assert!(!momentum.is_flinging());
+        assert!(!momentum.fling(now + FRAME * 3, &kinetic, |_| true));
+    }
+}
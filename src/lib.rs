@@ -1,12 +1,26 @@
+pub mod data_source;
 mod filter_wrapper;
 pub mod flatlist;
-mod new_scrollable;
+pub mod kinetic;
+pub mod new_scrollable;
+mod row_heights;
 pub mod scroller;
+pub mod sections;
+pub mod selection;
+pub mod skeleton;
+pub mod table;
 pub mod test_widget;
 // mod virtual_scroller;
 // pub use virtual_scroller::{Message, VirtualScroller, WithView};
 
 // #[doc(no_inline)]
-pub use flatlist::{flatlist, get_start_end_pos};
-pub use new_scrollable::NewScrollable;
+pub use data_source::{DataSource, MemorySource, Page};
+pub use flatlist::{
+    flatlist, get_start_end_pos, get_visible_range, scroll_to_index, scroll_to_index_smoothly,
+    Align, Axis, FlatList, ListViewport, Overscan, Threshold,
+};
+pub use new_scrollable::{snap_to, snap_to_smoothly, NewScrollable};
 pub use scroller::Scroller;
+pub use sections::{Position, Sections};
+pub use skeleton::Skeleton;
+pub use table::Table;
@@ -1,19 +1,25 @@
 //! Navigate an endless amount of content with a scrollbar.
+use std::any::Any;
+
 use iced_native::event::{self, Event};
 use iced_native::keyboard;
 use iced_native::layout;
 use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
+use iced_native::time::{Duration, Instant};
 use iced_native::touch;
 use iced_native::widget;
 use iced_native::widget::operation::{self, Operation};
 use iced_native::widget::tree::{self, Tree};
+use iced_native::window;
 use iced_native::{
     Background, Clipboard, Color, Command, Element, Layout, Length, Pixels, Point, Rectangle,
     Shell, Size, Vector, Widget,
 };
 
+use crate::kinetic::{Kinetic, Momentum};
+
 pub use iced_style::scrollable::StyleSheet;
 pub use operation::scrollable::RelativeOffset;
 
@@ -39,6 +45,12 @@ where
     horizontal: Option<Properties>,
     content: Element<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
+    on_viewport_change: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    stick_to_end: bool,
+    auto_scroll: Option<AutoScroll>,
+    kinetic: Option<Kinetic>,
+    smooth: Option<Smooth>,
+    wheel: Wheel,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -57,6 +69,12 @@ where
             horizontal: None,
             content: content.into(),
             on_scroll: None,
+            on_viewport_change: None,
+            stick_to_end: false,
+            auto_scroll: None,
+            kinetic: None,
+            smooth: None,
+            wheel: Wheel::default(),
             style: Default::default(),
         }
     }
@@ -100,6 +118,51 @@ where
         self
     }
 
+    /// Sets a function to call when the [`Viewport`] of the [`Scrollable`]
+    /// changes.
+    ///
+    /// Unlike [`on_scroll`](Self::on_scroll), the function is also called when
+    /// the [`Scrollable`] or its contents are resized, even if the contents
+    /// fit.
+    pub fn on_viewport_change(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.on_viewport_change = Some(Box::new(f));
+        self
+    }
+
+    /// Makes the [`Scrollable`] start scrolled to the end and stay there as
+    /// its contents grow, unless it was scrolled away from the end.
+    pub fn stick_to_end(mut self) -> Self {
+        self.stick_to_end = true;
+        self
+    }
+
+    /// Scrolls the [`Scrollable`] while the mouse button is held close to its
+    /// edges, like when selecting text or dragging something out of view.
+    pub fn auto_scroll(mut self, auto_scroll: AutoScroll) -> Self {
+        self.auto_scroll = Some(auto_scroll);
+        self
+    }
+
+    /// Makes the [`Scrollable`] keep scrolling after it is flung with a
+    /// finger, slowing down as described by the given [`Kinetic`].
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.kinetic = Some(kinetic);
+        self
+    }
+
+    /// Animates the scrolls of the [`Scrollable`] by lines of the mouse wheel
+    /// and by pages, as described by the given [`Smooth`].
+    pub fn smooth(mut self, smooth: Smooth) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+
+    /// Sets how the [`Scrollable`] scrolls with the mouse wheel.
+    pub fn wheel(mut self, wheel: Wheel) -> Self {
+        self.wheel = wheel;
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
         self.style = style.into();
@@ -107,6 +170,312 @@ where
     }
 }
 
+/// How a [`Scrollable`] scrolls by itself while the mouse button is held
+/// close to its edges.
+///
+/// The closer the cursor is to an edge, the faster the [`Scrollable`] scrolls
+/// towards it, up to the maximum speed once the cursor reaches the edge. Only
+/// a drag scrolls: the cursor has to move a few pixels away from where the
+/// button was pressed first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoScroll {
+    edge: f32,
+    max_speed: f32,
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        Self {
+            edge: 48.0,
+            max_speed: 1200.0,
+        }
+    }
+}
+
+impl AutoScroll {
+    /// The distance the cursor has to move while the button is held to start
+    /// a drag.
+    const DRAG_THRESHOLD: f32 = 4.0;
+
+    /// Creates a new [`AutoScroll`] for use in a [`Scrollable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of the zones along the edges of the [`Scrollable`] where
+    /// it starts scrolling.
+    pub fn edge(mut self, edge: impl Into<Pixels>) -> Self {
+        self.edge = edge.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the speed, in pixels per second, at which the [`Scrollable`]
+    /// scrolls when the cursor reaches its edges.
+    pub fn max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = max_speed.max(0.0);
+        self
+    }
+
+    /// Returns the velocity, in pixels per second, of a [`Scrollable`] with
+    /// the cursor held at `cursor_position`.
+    fn velocity(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Vector {
+        let speed = |start: f32, length: f32, content: f32, position: f32| {
+            if content <= length {
+                return 0.0;
+            }
+
+            let edge = self.edge.min(length / 2.0);
+            let along = position - start;
+
+            let proximity = if along < edge {
+                -(edge - along) / edge
+            } else if along > length - edge {
+                (along - (length - edge)) / edge
+            } else {
+                0.0
+            };
+
+            proximity.clamp(-1.0, 1.0) * self.max_speed
+        };
+
+        Vector::new(
+            speed(
+                bounds.x,
+                bounds.width,
+                content_bounds.width,
+                cursor_position.x,
+            ),
+            speed(
+                bounds.y,
+                bounds.height,
+                content_bounds.height,
+                cursor_position.y,
+            ),
+        )
+    }
+}
+
+/// How a [`Scrollable`] animates its scrolls.
+#[derive(Debug, Clone, Copy)]
+pub struct Smooth {
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Default for Smooth {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(150),
+            easing: Easing::default(),
+        }
+    }
+}
+
+impl Smooth {
+    /// Creates a new [`Smooth`] for use in a [`Scrollable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long an animated scroll takes.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the animated scrolls.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// The curve followed by an animated scroll.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts fast and slows down towards the end.
+    #[default]
+    EaseOut,
+    /// Speeds up, then slows down towards the end.
+    EaseInOut,
+    /// Maps the elapsed fraction of the animation, from `0` to `1`, to the
+    /// covered fraction of the distance.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Returns the covered fraction of the distance once `progress` of the
+    /// animation has elapsed.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t.powi(3),
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// How a [`Scrollable`] scrolls with the mouse wheel.
+///
+/// A mouse wheel scrolls by lines, each of which scrolls the content by a
+/// fixed amount of pixels or by a number of rows. A touchpad scrolls by
+/// pixels, which can be scaled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wheel {
+    line_height: f32,
+    row_height: Option<f32>,
+    pixel_multiplier: f32,
+    inverted: bool,
+    shift_for_horizontal: bool,
+    snaps_from_end: bool,
+}
+
+impl Default for Wheel {
+    fn default() -> Self {
+        Self {
+            line_height: 60.0,
+            row_height: None,
+            pixel_multiplier: 1.0,
+            inverted: false,
+            shift_for_horizontal: true,
+            snaps_from_end: false,
+        }
+    }
+}
+
+impl Wheel {
+    /// Creates a new [`Wheel`] for use in a [`Scrollable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many pixels a line of the mouse wheel scrolls by.
+    pub fn line_height(mut self, line_height: impl Into<Pixels>) -> Self {
+        self.line_height = line_height.into().0.max(0.0);
+        self.row_height = None;
+        self
+    }
+
+    /// Makes every line of the mouse wheel scroll by `count` rows of
+    /// `row_height` pixels, snapping the content to the closest row.
+    pub fn rows(mut self, count: usize, row_height: impl Into<Pixels>) -> Self {
+        let row_height = row_height.into().0.max(0.0);
+
+        self.line_height = count as f32 * row_height;
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Sets the factor applied to the pixels scrolled by a touchpad.
+    pub fn pixel_multiplier(mut self, pixel_multiplier: f32) -> Self {
+        self.pixel_multiplier = pixel_multiplier;
+        self
+    }
+
+    /// Sets whether the wheel scrolls the content in the opposite direction.
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Sets whether holding Shift turns vertical lines of the mouse wheel
+    /// into horizontal ones.
+    pub fn shift_for_horizontal(mut self, shift_for_horizontal: bool) -> Self {
+        self.shift_for_horizontal = shift_for_horizontal;
+        self
+    }
+
+    /// Returns the pixels a [`mouse::ScrollDelta`] scrolls the content by,
+    /// given the keyboard modifiers held.
+    pub fn delta(&self, delta: mouse::ScrollDelta, modifiers: keyboard::Modifiers) -> Vector {
+        let delta = match delta {
+            mouse::ScrollDelta::Lines { x, y } => {
+                let movement = if self.shift_for_horizontal && modifiers.shift() {
+                    Vector::new(y, x)
+                } else {
+                    Vector::new(x, y)
+                };
+
+                movement * self.line_height
+            }
+            mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y) * self.pixel_multiplier,
+        };
+
+        if self.inverted {
+            delta * -1.0
+        } else {
+            delta
+        }
+    }
+
+    /// Sets whether the rows are lined up with the end of the content, like
+    /// the rows of a list laid out from its end, instead of its start.
+    pub(crate) fn snap_from_end(mut self, snaps_from_end: bool) -> Self {
+        self.snaps_from_end = snaps_from_end;
+        self
+    }
+
+    /// Snaps an `offset` reached by scrolling `delta` pixels to the closest
+    /// row, along the axes that moved, given the offset `end` of the content.
+    pub(crate) fn snap(&self, offset: Vector, delta: Vector, end: Vector) -> Vector {
+        let snap = |offset: f32, delta: f32, end: f32| match self.row_height {
+            Some(row_height) if row_height > 0.0 && delta != 0.0 => {
+                let origin = if self.snaps_from_end { end } else { 0.0 };
+
+                origin + ((offset - origin) / row_height).round() * row_height
+            }
+            _ => offset,
+        };
+
+        Vector::new(
+            snap(offset.x, delta.x, end.x),
+            snap(offset.y, delta.y, end.y),
+        )
+    }
+}
+
+/// An animated scroll in flight.
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    /// The offset the animation started from, known on its first frame.
+    from: Option<Vector>,
+    to_x: Offset,
+    to_y: Offset,
+    started_at: Option<Instant>,
+    smooth: Smooth,
+}
+
+impl Animation {
+    fn new(to_x: Offset, to_y: Offset, smooth: Smooth) -> Self {
+        Self {
+            from: None,
+            to_x,
+            to_y,
+            started_at: None,
+            smooth,
+        }
+    }
+
+    /// Returns the absolute offset the animation ends at.
+    fn target(&self, bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+        Vector::new(
+            self.to_x.absolute(bounds.width, content_bounds.width),
+            self.to_y.absolute(bounds.height, content_bounds.height),
+        )
+    }
+}
+
 /// Properties of a scrollbar within a [`Scrollable`].
 #[derive(Debug)]
 pub struct Properties {
@@ -162,7 +531,17 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        let mut state = State::new();
+
+        if self.stick_to_end {
+            state.stick_to_end();
+        }
+
+        state.set_kinetic(self.kinetic);
+        state.set_smooth(self.smooth);
+        state.set_wheel(self.wheel);
+
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -170,6 +549,16 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.stick_to_end && !state.sticks_to_end() {
+            state.stick_to_end();
+        }
+
+        state.set_kinetic(self.kinetic);
+        state.set_smooth(self.smooth);
+        state.set_wheel(self.wheel);
+
         tree.diff_children(std::slice::from_ref(&self.content))
     }
 
@@ -202,6 +591,15 @@ where
         let state = tree.state.downcast_mut::<State>();
 
         operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
+        operation.custom(
+            &mut Viewport::new(
+                state,
+                layout.bounds(),
+                layout.children().next().unwrap().bounds(),
+            ),
+            self.id.as_ref().map(|id| &id.0),
+        );
+        operation.custom(state, self.id.as_ref().map(|id| &id.0));
 
         operation.container(self.id.as_ref().map(|id| &id.0), &mut |operation| {
             self.content.as_widget().operate(
@@ -223,8 +621,11 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        update(
-            tree.state.downcast_mut::<State>(),
+        let state = tree.state.downcast_mut::<State>();
+        let input = event.clone();
+
+        let status = update(
+            state,
             event,
             layout,
             cursor_position,
@@ -244,7 +645,46 @@ where
                     shell,
                 )
             },
-        )
+        );
+
+        let status = match input {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if status == event::Status::Ignored
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                page(
+                    state,
+                    key_code,
+                    layout.bounds(),
+                    layout.children().next().unwrap().bounds(),
+                    &self.on_scroll,
+                    shell,
+                )
+            }
+            _ => status,
+        };
+
+        if let Some(auto_scroll) = &self.auto_scroll {
+            self::auto_scroll(
+                state,
+                &input,
+                layout,
+                cursor_position,
+                auto_scroll,
+                &self.on_scroll,
+                shell,
+            );
+        }
+
+        notify_on_viewport_change(
+            state,
+            &self.on_viewport_change,
+            layout.bounds(),
+            layout.children().next().unwrap().bounds(),
+            shell,
+        );
+
+        status
     }
 
     fn draw(
@@ -347,7 +787,7 @@ where
 
 /// The identifier of a [`Scrollable`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(widget::Id);
+pub struct Id(pub(crate) widget::Id);
 
 impl Id {
     /// Creates a custom [`Id`].
@@ -371,10 +811,155 @@ impl From<Id> for widget::Id {
 
 /// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
 /// to the provided `percentage` along the x & y axis.
-pub fn _snap_to<Message: 'static>(id: Id, offset: RelativeOffset) -> Command<Message> {
+pub fn snap_to<Message: 'static>(id: Id, offset: RelativeOffset) -> Command<Message> {
     Command::widget(operation::scrollable::snap_to(id.0, offset))
 }
 
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to the provided `percentage` along the x & y axis, animated as
+/// described by its [`Smooth`], or the default one.
+pub fn snap_to_smoothly<Message: 'static>(id: Id, offset: RelativeOffset) -> Command<Message> {
+    struct SnapToSmoothly {
+        target: widget::Id,
+        offset: RelativeOffset,
+    }
+
+    impl<T> Operation<T> for SnapToSmoothly {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                state.snap_to_smoothly(self.offset);
+            }
+        }
+    }
+
+    Command::widget(SnapToSmoothly {
+        target: id.0,
+        offset,
+    })
+}
+
+/// The scroll position of a [`Scrollable`], along with the sizes it depends
+/// on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The absolute offset of the content, in pixels.
+    pub offset: Vector,
+    /// The offset of the content, from `0` at the beginning to `1` at the
+    /// end. It is `0` along the axes where the content fits.
+    pub relative_offset: RelativeOffset,
+    /// The bounds of the visible part of the content.
+    pub bounds: Rectangle,
+    /// The bounds of the content, before it is scrolled.
+    pub content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Captures the [`Viewport`] of a [`Scrollable`], given its [`State`] and
+    /// the bounds of the [`Scrollable`] and its contents.
+    pub fn new(state: &State, bounds: Rectangle, content_bounds: Rectangle) -> Self {
+        let offset = state.offset(bounds, content_bounds);
+        let relative = |offset: f32, window: f32, content: f32| {
+            if content > window {
+                offset / (content - window)
+            } else {
+                0.0
+            }
+        };
+
+        Self {
+            offset,
+            relative_offset: RelativeOffset {
+                x: relative(offset.x, bounds.width, content_bounds.width),
+                y: relative(offset.y, bounds.height, content_bounds.height),
+            },
+            bounds,
+            content_bounds,
+        }
+    }
+
+    /// Returns whether the content is scrolled to the end along every axis it
+    /// overflows.
+    pub fn is_at_end(&self) -> bool {
+        let remaining = |offset: f32, window: f32, content: f32| content - window - offset;
+
+        remaining(self.offset.x, self.bounds.width, self.content_bounds.width) < 0.5
+            && remaining(
+                self.offset.y,
+                self.bounds.height,
+                self.content_bounds.height,
+            ) < 0.5
+    }
+
+    /// Returns the size of the visible part of the content.
+    pub fn size(&self) -> Size {
+        self.bounds.size()
+    }
+
+    /// Returns the size of the content.
+    pub fn content_size(&self) -> Size {
+        self.content_bounds.size()
+    }
+}
+
+/// Produces an [`Operation`] that reads the [`Viewport`] of the [`Scrollable`]
+/// with the given [`Id`].
+///
+/// The [`Operation`] outputs `None` if no such [`Scrollable`] is found.
+pub fn query_viewport(id: Id) -> impl Operation<Option<Viewport>> {
+    struct QueryViewport {
+        target: widget::Id,
+        viewport: Option<Viewport>,
+    }
+
+    impl Operation<Option<Viewport>> for QueryViewport {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<Viewport>>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id == Some(&self.target) {
+                if let Some(viewport) = state.downcast_ref::<Viewport>() {
+                    self.viewport = Some(*viewport);
+                }
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Option<Viewport>> {
+            operation::Outcome::Some(self.viewport)
+        }
+    }
+
+    QueryViewport {
+        target: id.0,
+        viewport: None,
+    }
+}
+
+/// Produces a [`Command`] that reads the [`Viewport`] of the [`Scrollable`]
+/// with the given [`Id`] and turns it into a message.
+pub fn viewport<Message: 'static>(
+    id: Id,
+    f: impl Fn(Option<Viewport>) -> Message + Clone + Send + Sync + 'static,
+) -> Command<Message> {
+    Command::widget(query_viewport(id)).map(f)
+}
+
 /// Computes the layout of a [`Scrollable`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -435,6 +1020,13 @@ pub fn update<Message>(
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor_position);
 
+    // Any new press stops a fling, even if the content captures it.
+    if let Event::Mouse(mouse::Event::ButtonPressed(_))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        state.momentum.stop();
+    }
+
     let event_status = {
         let cursor_position =
             if mouse_over_scrollable && !(mouse_over_y_scrollbar || mouse_over_x_scrollbar) {
@@ -462,36 +1054,59 @@ pub fn update<Message>(
         return event::Status::Ignored;
     }
 
-    if mouse_over_scrollable {
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        fling(state, now, bounds, content_bounds, on_scroll, shell);
+        animate(state, now, bounds, content_bounds, on_scroll, shell);
+
+        return event::Status::Ignored;
+    }
+
+    // A finger keeps dragging the content past the edges of the scrollable,
+    // so a swipe lifted outside still ends the touch and flings the content.
+    let is_touched = state.scroll_area_touched_at.is_some();
+
+    if mouse_over_scrollable || is_touched {
         match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                let delta = match delta {
-                    mouse::ScrollDelta::Lines { x, y } => {
-                        // TODO: Configurable speed/friction (?)
-                        let movement = if state.keyboard_modifiers.shift() {
-                            Vector::new(y, x)
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if mouse_over_scrollable => {
+                let delta_pixels = state.wheel.delta(delta, state.keyboard_modifiers);
+
+                match delta {
+                    mouse::ScrollDelta::Lines { .. } => {
+                        let from = state.destination(bounds, content_bounds);
+                        let end = Vector::new(
+                            (content_bounds.width - bounds.width).max(0.0),
+                            (content_bounds.height - bounds.height).max(0.0),
+                        );
+                        let to = state.wheel.snap(from - delta_pixels, delta_pixels, end);
+
+                        if state.smooth.is_some() {
+                            state.scroll_smoothly_to(to, bounds, content_bounds);
+                            shell.request_redraw(window::RedrawRequest::NextFrame);
                         } else {
-                            Vector::new(x, y)
-                        };
+                            state.scroll(from - to, bounds, content_bounds);
 
-                        movement * 60.0
+                            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+                        }
                     }
-                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
-                };
+                    mouse::ScrollDelta::Pixels { .. } => {
+                        state.scroll(delta_pixels, bounds, content_bounds);
 
-                state.scroll(delta, bounds, content_bounds);
-
-                notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+                        notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+                    }
+                }
 
                 return event::Status::Captured;
             }
             Event::Touch(event)
-                if state.scroll_area_touched_at.is_some()
-                    || !mouse_over_y_scrollbar && !mouse_over_x_scrollbar =>
+                if is_touched || !mouse_over_y_scrollbar && !mouse_over_x_scrollbar =>
             {
                 match event {
                     touch::Event::FingerPressed { .. } => {
                         state.scroll_area_touched_at = Some(cursor_position);
+
+                        if let Some(kinetic) = state.kinetic {
+                            state.momentum.press(kinetic.now());
+                        }
                     }
                     touch::Event::FingerMoved { .. } => {
                         if let Some(scroll_box_touched_at) = state.scroll_area_touched_at {
@@ -504,11 +1119,25 @@ pub fn update<Message>(
 
                             state.scroll_area_touched_at = Some(cursor_position);
 
+                            if let Some(kinetic) = state.kinetic {
+                                state.momentum.track(delta, kinetic.now());
+                            }
+
                             notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
                         }
                     }
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. } => {
+                    touch::Event::FingerLifted { .. } => {
+                        state.scroll_area_touched_at = None;
+
+                        if let Some(kinetic) = state.kinetic {
+                            if state.momentum.release(kinetic.now(), &kinetic) {
+                                shell.request_redraw(window::RedrawRequest::NextFrame);
+                            }
+                        }
+                    }
+                    touch::Event::FingerLost { .. } => {
                         state.scroll_area_touched_at = None;
+                        state.momentum.stop();
                     }
                 }
 
@@ -820,24 +1449,186 @@ pub fn draw<Renderer>(
     }
 }
 
-fn notify_on_scroll<Message>(
+/// Advances the animation of a [`Scrollable`] to `now`, if it is animating.
+fn animate<Message>(
     state: &mut State,
-    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    now: Instant,
     bounds: Rectangle,
     content_bounds: Rectangle,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
     shell: &mut Shell<'_, Message>,
 ) {
-    if let Some(on_scroll) = on_scroll {
-        if content_bounds.width <= bounds.width && content_bounds.height <= bounds.height {
-            return;
-        }
+    if !state.advance(now, bounds, content_bounds) {
+        return;
+    }
 
-        let x = state.offset_x.absolute(bounds.width, content_bounds.width)
-            / (content_bounds.width - bounds.width);
+    notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
 
-        let y = state
-            .offset_y
-            .absolute(bounds.height, content_bounds.height)
+    if state.is_animating() {
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+/// Scrolls a [`Scrollable`] by a page if `key_code` is PageUp or PageDown.
+fn page<Message>(
+    state: &mut State,
+    key_code: keyboard::KeyCode,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status {
+    let direction = match key_code {
+        keyboard::KeyCode::PageUp => 1.0,
+        keyboard::KeyCode::PageDown => -1.0,
+        _ => return event::Status::Ignored,
+    };
+
+    // Content that only scrolls sideways is paged sideways.
+    let delta = if content_bounds.height <= bounds.height && content_bounds.width > bounds.width {
+        Vector::new(direction * bounds.width, 0.0)
+    } else {
+        Vector::new(0.0, direction * bounds.height)
+    };
+
+    if state.smooth.is_some() {
+        state.scroll_smoothly(delta, bounds, content_bounds);
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    } else {
+        state.scroll(delta, bounds, content_bounds);
+
+        notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+    }
+
+    event::Status::Captured
+}
+
+/// Advances the fling of a [`Scrollable`] to `now`, if it is flinging.
+fn fling<Message>(
+    state: &mut State,
+    now: Instant,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    shell: &mut Shell<'_, Message>,
+) {
+    let Some(kinetic) = state.kinetic else {
+        return;
+    };
+
+    let mut momentum = state.momentum;
+    let is_flinging = momentum.fling(now, &kinetic, |delta| {
+        let offset = state.offset(bounds, content_bounds);
+
+        state.scroll(delta, bounds, content_bounds);
+        state.offset(bounds, content_bounds) != offset
+    });
+
+    state.momentum = momentum;
+
+    if !is_flinging {
+        return;
+    }
+
+    notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+
+    if state.momentum.is_flinging() {
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+/// Scrolls a [`Scrollable`] while the mouse button is held close to its
+/// edges, given the [`Event`] it just processed.
+///
+/// The [`State`] requests a redraw every frame while the cursor stays in the
+/// edge zones, and scrolls further whenever the redraw happens.
+pub fn auto_scroll<Message>(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    auto_scroll: &AutoScroll,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    shell: &mut Shell<'_, Message>,
+) {
+    let bounds = layout.bounds();
+    let content_bounds = layout.children().next().unwrap().bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            if bounds.contains(cursor_position) && !state.scrollers_grabbed() =>
+        {
+            state.pressed_at = Some(cursor_position);
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            state.pressed_at = None;
+            state.held_at = None;
+            state.auto_scrolled_at = None;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            // A click held still is not a drag, even close to the edges.
+            let is_dragging = state.held_at.is_some()
+                || state.pressed_at.is_some_and(|pressed_at| {
+                    pressed_at.distance(*position) >= AutoScroll::DRAG_THRESHOLD
+                });
+
+            if !is_dragging {
+                return;
+            }
+
+            state.held_at = Some(*position);
+
+            let velocity = auto_scroll.velocity(bounds, content_bounds, *position);
+
+            if velocity != Vector::new(0.0, 0.0) && state.auto_scrolled_at.is_none() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            let Some(held_at) = state.held_at else {
+                return;
+            };
+
+            let velocity = auto_scroll.velocity(bounds, content_bounds, held_at);
+
+            if velocity == Vector::new(0.0, 0.0) {
+                state.auto_scrolled_at = None;
+                return;
+            }
+
+            if let Some(last) = state.auto_scrolled_at {
+                // Long pauses between frames should not jump far away.
+                let elapsed = now.saturating_duration_since(last).as_secs_f32().min(0.1);
+
+                state.scroll(velocity * -elapsed, bounds, content_bounds);
+                notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+            }
+
+            state.auto_scrolled_at = Some(*now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+        _ => {}
+    }
+}
+
+fn notify_on_scroll<Message>(
+    state: &mut State,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some(on_scroll) = on_scroll {
+        if content_bounds.width <= bounds.width && content_bounds.height <= bounds.height {
+            return;
+        }
+
+        let x = state.offset_x.absolute(bounds.width, content_bounds.width)
+            / (content_bounds.width - bounds.width);
+
+        let y = state
+            .offset_y
+            .absolute(bounds.height, content_bounds.height)
             / (content_bounds.height - bounds.height);
 
         let new_offset = RelativeOffset { x, y };
@@ -857,6 +1648,23 @@ fn notify_on_scroll<Message>(
     }
 }
 
+fn notify_on_viewport_change<Message>(
+    state: &mut State,
+    on_viewport_change: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some(on_viewport_change) = on_viewport_change {
+        let viewport = Viewport::new(state, bounds, content_bounds);
+
+        if state.last_viewport != Some(viewport) {
+            shell.publish(on_viewport_change(viewport));
+            state.last_viewport = Some(viewport);
+        }
+    }
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -867,6 +1675,16 @@ pub struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<RelativeOffset>,
+    last_viewport: Option<Viewport>,
+    stick_to_end: bool,
+    pressed_at: Option<Point>,
+    held_at: Option<Point>,
+    auto_scrolled_at: Option<Instant>,
+    kinetic: Option<Kinetic>,
+    momentum: Momentum,
+    smooth: Option<Smooth>,
+    animation: Option<Animation>,
+    wheel: Wheel,
 }
 
 impl Default for State {
@@ -879,6 +1697,16 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            last_viewport: None,
+            stick_to_end: false,
+            pressed_at: None,
+            held_at: None,
+            auto_scrolled_at: None,
+            kinetic: None,
+            momentum: Momentum::default(),
+            smooth: None,
+            animation: None,
+            wheel: Wheel::default(),
         }
     }
 }
@@ -913,6 +1741,8 @@ impl State {
     /// Apply a scrolling offset to the current [`State`], given the bounds of
     /// the [`Scrollable`] and its contents.
     pub fn scroll(&mut self, delta: Vector<f32>, bounds: Rectangle, content_bounds: Rectangle) {
+        self.animation = None;
+
         if bounds.height < content_bounds.height {
             self.offset_y = Offset::Absolute(
                 (self.offset_y.absolute(bounds.height, content_bounds.height) - delta.y)
@@ -926,6 +1756,8 @@ impl State {
                     .clamp(0.0, content_bounds.width - bounds.width),
             );
         }
+
+        self.stick(bounds, content_bounds);
     }
 
     /// Scrolls the [`Scrollable`] to a relative amount along the y axis.
@@ -933,8 +1765,10 @@ impl State {
     /// `0` represents scrollbar at the beginning, while `1` represents scrollbar at
     /// the end.
     pub fn scroll_y_to(&mut self, percentage: f32, bounds: Rectangle, content_bounds: Rectangle) {
+        self.animation = None;
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
+        self.stick(bounds, content_bounds);
     }
 
     /// Scrolls the [`Scrollable`] to a relative amount along the x axis.
@@ -942,12 +1776,15 @@ impl State {
     /// `0` represents scrollbar at the beginning, while `1` represents scrollbar at
     /// the end.
     pub fn scroll_x_to(&mut self, percentage: f32, bounds: Rectangle, content_bounds: Rectangle) {
+        self.animation = None;
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
+        self.stick(bounds, content_bounds);
     }
 
     /// Snaps the scroll position to a [`RelativeOffset`].
     pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.animation = None;
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
@@ -970,6 +1807,172 @@ impl State {
         )
     }
 
+    /// Scrolls the [`Scrollable`] to the end and keeps it there as its
+    /// contents grow, until it is scrolled away from the end.
+    ///
+    /// Scrolling back to the end sticks it to the end again.
+    pub fn stick_to_end(&mut self) {
+        self.stick_to_end = true;
+        self.snap_to(RelativeOffset::END);
+    }
+
+    /// Returns whether the [`Scrollable`] sticks to the end while it is there.
+    pub fn sticks_to_end(&self) -> bool {
+        self.stick_to_end
+    }
+
+    /// Returns whether the [`Scrollable`] is scrolled to the end along every
+    /// axis its contents overflow, given its bounds and those of its contents.
+    pub fn is_at_end(&self, bounds: Rectangle, content_bounds: Rectangle) -> bool {
+        Viewport::new(self, bounds, content_bounds).is_at_end()
+    }
+
+    /// Keeps the axes that reached the end at the end, if the [`Scrollable`]
+    /// sticks to the end.
+    fn stick(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
+        if !self.stick_to_end {
+            return;
+        }
+
+        let offset = self.offset(bounds, content_bounds);
+
+        if offset.y >= content_bounds.height - bounds.height {
+            self.offset_y = Offset::Relative(1.0);
+        }
+
+        if offset.x >= content_bounds.width - bounds.width {
+            self.offset_x = Offset::Relative(1.0);
+        }
+    }
+
+    /// Sets how the [`Scrollable`] keeps scrolling after a fling, if at all.
+    pub fn set_kinetic(&mut self, kinetic: Option<Kinetic>) {
+        self.kinetic = kinetic;
+
+        if kinetic.is_none() {
+            self.momentum.stop();
+        }
+    }
+
+    /// Sets how the [`Scrollable`] animates its scrolls, if at all.
+    pub fn set_smooth(&mut self, smooth: Option<Smooth>) {
+        self.smooth = smooth;
+    }
+
+    /// Sets how the [`Scrollable`] scrolls with the mouse wheel.
+    pub fn set_wheel(&mut self, wheel: Wheel) {
+        self.wheel = wheel;
+    }
+
+    /// Returns `true` if the [`Scrollable`] is in the middle of an animated
+    /// scroll.
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    /// Scrolls the [`Scrollable`] by `delta` with an animation, continuing
+    /// from where the animation in flight, if any, would end.
+    pub fn scroll_smoothly(
+        &mut self,
+        delta: Vector<f32>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let target = self.destination(bounds, content_bounds);
+
+        self.scroll_smoothly_to(target - delta, bounds, content_bounds);
+    }
+
+    /// Returns the absolute offset the [`Scrollable`] ends at, once the
+    /// animation in flight, if any, is over.
+    fn destination(&self, bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+        match &self.animation {
+            Some(animation) => animation.target(bounds, content_bounds),
+            None => self.offset(bounds, content_bounds),
+        }
+    }
+
+    /// Scrolls the [`Scrollable`] to the absolute `offset` with an animation.
+    pub fn scroll_smoothly_to(
+        &mut self,
+        offset: Vector<f32>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let x = offset
+            .x
+            .clamp(0.0, (content_bounds.width - bounds.width).max(0.0));
+        let y = offset
+            .y
+            .clamp(0.0, (content_bounds.height - bounds.height).max(0.0));
+
+        self.animation = Some(Animation {
+            from: Some(self.offset(bounds, content_bounds)),
+            ..Animation::new(
+                Offset::Absolute(x),
+                Offset::Absolute(y),
+                self.smooth.unwrap_or_default(),
+            )
+        });
+    }
+
+    /// Snaps the [`Scrollable`] to a relative offset with an animation.
+    pub fn snap_to_smoothly(&mut self, offset: RelativeOffset) {
+        self.animation = Some(Animation::new(
+            Offset::Relative(offset.x.clamp(0.0, 1.0)),
+            Offset::Relative(offset.y.clamp(0.0, 1.0)),
+            self.smooth.unwrap_or_default(),
+        ));
+    }
+
+    /// Advances the animated scroll in flight, if any, to `now`.
+    ///
+    /// Returns `true` if the offset changed.
+    pub fn advance(&mut self, now: Instant, bounds: Rectangle, content_bounds: Rectangle) -> bool {
+        let Some(mut animation) = self.animation else {
+            return false;
+        };
+
+        let from = *animation
+            .from
+            .get_or_insert_with(|| self.offset(bounds, content_bounds));
+        let started_at = *animation.started_at.get_or_insert(now);
+
+        let duration = animation.smooth.duration.as_secs_f32();
+        let progress = if duration > 0.0 {
+            now.saturating_duration_since(started_at).as_secs_f32() / duration
+        } else {
+            1.0
+        };
+
+        if progress >= 1.0 {
+            self.offset_x = animation.to_x;
+            self.offset_y = animation.to_y;
+            self.animation = None;
+        } else {
+            let to = animation.target(bounds, content_bounds);
+            let offset = from + (to - from) * animation.smooth.easing.apply(progress);
+
+            self.offset_x = Offset::Absolute(offset.x);
+            self.offset_y = Offset::Absolute(offset.y);
+            self.animation = Some(animation);
+        }
+
+        self.stick(bounds, content_bounds);
+
+        true
+    }
+
+    /// Returns `true` if the [`Scrollable`] is moving after a fling.
+    pub fn is_flinging(&self) -> bool {
+        self.momentum.is_flinging()
+    }
+
+    /// Returns the keyboard modifiers last seen by the [`Scrollable`].
+    pub fn keyboard_modifiers(&self) -> keyboard::Modifiers {
+        self.keyboard_modifiers
+    }
+
     /// Returns whether any scroller is currently grabbed or not.
     pub fn scrollers_grabbed(&self) -> bool {
         self.x_scroller_grabbed_at.is_some() || self.y_scroller_grabbed_at.is_some()
@@ -1212,3 +2215,356 @@ struct Scroller {
     /// The bounds of the [`Scroller`].
     bounds: Rectangle,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{auto_scroll, update, AutoScroll, Properties, State};
+
+    use std::cell::Cell;
+    use std::sync::OnceLock;
+
+    use iced_native::clipboard;
+    use iced_native::event::{self, Event};
+    use iced_native::layout;
+    use iced_native::mouse;
+    use iced_native::time::{Duration, Instant};
+    use iced_native::touch::{self, Finger};
+    use iced_native::window;
+    use iced_native::{Layout, Point, Rectangle, Shell, Size, Vector};
+
+    use crate::kinetic::Kinetic;
+
+    thread_local! {
+        static ELAPSED: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    }
+
+    /// A clock that only moves when a test tells it to.
+    fn now() -> Instant {
+        static START: OnceLock<Instant> = OnceLock::new();
+
+        *START.get_or_init(Instant::now) + ELAPSED.with(Cell::get)
+    }
+
+    fn wait(duration: Duration) {
+        ELAPSED.with(|elapsed| elapsed.set(elapsed.get() + duration));
+    }
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 1000.0,
+    };
+
+    /// A [`State`] that flings, as timed by the test clock.
+    fn kinetic() -> State {
+        let mut state = State::new();
+        state.set_kinetic(Some(Kinetic::new().clock(now)));
+        state
+    }
+
+    /// Sends an [`Event`] to a [`State`] scrolling 1000 pixels of content in
+    /// a viewport of 100, with the cursor at `y`.
+    ///
+    /// Returns the status of the event and whether a redraw was requested.
+    fn send(state: &mut State, event: Event, y: f32) -> (event::Status, bool) {
+        let node = node();
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = update(
+            state,
+            event,
+            Layout::new(&node),
+            Point::new(50.0, y),
+            &mut clipboard::Null,
+            &mut shell,
+            &Properties::default(),
+            None,
+            &None,
+            |_, _, _, _, _| event::Status::Ignored,
+        );
+
+        (status, shell.redraw_request().is_some())
+    }
+
+    fn node() -> layout::Node {
+        layout::Node::with_children(
+            BOUNDS.size(),
+            vec![layout::Node::new(Size::new(100.0, 1000.0))],
+        )
+    }
+
+    /// Hands an [`Event`] to the [`auto_scroll`] of a [`State`] scrolling the
+    /// same content as [`send`], with the cursor at `y`.
+    fn hold(state: &mut State, event: Event, y: f32) {
+        let node = node();
+        let mut messages: Vec<()> = Vec::new();
+
+        auto_scroll(
+            state,
+            &event,
+            Layout::new(&node),
+            Point::new(50.0, y),
+            &AutoScroll::default(),
+            &None,
+            &mut Shell::new(&mut messages),
+        );
+    }
+
+    /// Redraws the [`auto_scroll`] of a [`State`] three times.
+    fn hold_still(state: &mut State, y: f32) {
+        for _ in 0..3 {
+            wait(Duration::from_millis(16));
+            hold(
+                state,
+                Event::Window(window::Event::RedrawRequested(now())),
+                y,
+            );
+        }
+    }
+
+    fn mouse(event: mouse::Event) -> Event {
+        Event::Mouse(event)
+    }
+
+    fn cursor_moved(y: f32) -> Event {
+        mouse(mouse::Event::CursorMoved {
+            position: Point::new(50.0, y),
+        })
+    }
+
+    #[test]
+    fn auto_scroll_ignores_a_press_held_still() {
+        let mut state = State::new();
+
+        hold(
+            &mut state,
+            mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            95.0,
+        );
+        hold_still(&mut state, 95.0);
+
+        hold(&mut state, cursor_moved(97.0), 97.0);
+        hold_still(&mut state, 97.0);
+
+        assert_eq!(offset(&state), 0.0);
+    }
+
+    #[test]
+    fn auto_scroll_follows_a_drag_to_the_edge() {
+        let mut state = State::new();
+
+        hold(
+            &mut state,
+            mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            50.0,
+        );
+        hold(&mut state, cursor_moved(95.0), 95.0);
+        hold_still(&mut state, 95.0);
+
+        let scrolled = offset(&state);
+        assert!(scrolled > 0.0);
+
+        hold(
+            &mut state,
+            mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            95.0,
+        );
+        hold_still(&mut state, 95.0);
+
+        assert_eq!(offset(&state), scrolled);
+    }
+
+    fn finger(event: fn(Finger, Point) -> touch::Event, y: f32) -> Event {
+        Event::Touch(event(Finger(0), Point::new(50.0, y)))
+    }
+
+    fn pressed(id: Finger, position: Point) -> touch::Event {
+        touch::Event::FingerPressed { id, position }
+    }
+
+    fn moved(id: Finger, position: Point) -> touch::Event {
+        touch::Event::FingerMoved { id, position }
+    }
+
+    fn lifted(id: Finger, position: Point) -> touch::Event {
+        touch::Event::FingerLifted { id, position }
+    }
+
+    fn lost(id: Finger, position: Point) -> touch::Event {
+        touch::Event::FingerLost { id, position }
+    }
+
+    /// Drags the content up by a finger, 10 pixels every 10 milliseconds,
+    /// starting `from` the given height.
+    fn swipe(state: &mut State, from: f32, frames: u32) -> f32 {
+        send(state, finger(pressed, from), from);
+
+        (1..=frames).fold(from, |_, frame| {
+            let y = from - 10.0 * frame as f32;
+
+            wait(Duration::from_millis(10));
+            send(state, finger(moved, y), y);
+            y
+        })
+    }
+
+    fn redraw(state: &mut State) -> (event::Status, bool) {
+        wait(Duration::from_millis(16));
+
+        send(
+            state,
+            Event::Window(window::Event::RedrawRequested(now())),
+            0.0,
+        )
+    }
+
+    fn offset(state: &State) -> f32 {
+        state.offset(BOUNDS, CONTENT_BOUNDS).y
+    }
+
+    #[test]
+    fn fling_keeps_scrolling_after_the_finger_is_lifted() {
+        let mut state = kinetic();
+        let y = swipe(&mut state, 90.0, 5);
+
+        assert_eq!(offset(&state), 50.0);
+        assert!(!state.is_flinging());
+
+        let (status, redraw_requested) = send(&mut state, finger(lifted, y), y);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(redraw_requested);
+        assert!(state.is_flinging());
+
+        let (status, redraw_requested) = redraw(&mut state);
+        let first = offset(&state);
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(redraw_requested);
+        assert!(first > 50.0);
+
+        redraw(&mut state);
+        let second = offset(&state);
+
+        assert!(second > first);
+        assert!(second - first < first - 50.0, "the fling slows down");
+    }
+
+    #[test]
+    fn fling_after_a_swipe_lifted_outside() {
+        let mut state = kinetic();
+        swipe(&mut state, 90.0, 5);
+
+        wait(Duration::from_millis(10));
+        send(&mut state, finger(moved, -10.0), -10.0);
+
+        assert_eq!(offset(&state), 100.0, "the finger drags past the edge");
+
+        let (status, redraw_requested) = send(&mut state, finger(lifted, -10.0), -10.0);
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(redraw_requested);
+        assert!(state.is_flinging());
+
+        redraw(&mut state);
+        assert!(offset(&state) > 100.0);
+    }
+
+    #[test]
+    fn touch_ends_when_lost_outside() {
+        let mut state = kinetic();
+        swipe(&mut state, 90.0, 5);
+
+        send(&mut state, finger(lost, 150.0), 150.0);
+
+        assert!(!state.is_flinging());
+
+        // The touch is over, so moving outside no longer drags the content.
+        send(&mut state, finger(moved, 250.0), 250.0);
+        assert_eq!(offset(&state), 50.0);
+    }
+
+    #[test]
+    fn fling_comes_to_rest() {
+        let mut state = kinetic();
+        let y = swipe(&mut state, 90.0, 5);
+
+        send(&mut state, finger(lifted, y), y);
+
+        for _ in 0..1000 {
+            if !redraw(&mut state).1 {
+                break;
+            }
+        }
+
+        let rest = offset(&state);
+
+        assert!(!state.is_flinging());
+        assert!(!redraw(&mut state).1);
+        assert_eq!(offset(&state), rest);
+    }
+
+    #[test]
+    fn fling_stops_at_the_end() {
+        let mut state = kinetic();
+        state.scroll(Vector::new(0.0, -850.0), BOUNDS, CONTENT_BOUNDS);
+
+        let y = swipe(&mut state, 90.0, 5);
+        send(&mut state, finger(lifted, y), y);
+
+        for _ in 0..1000 {
+            if !redraw(&mut state).1 {
+                break;
+            }
+        }
+
+        assert_eq!(offset(&state), 900.0);
+        assert!(!state.is_flinging());
+    }
+
+    #[test]
+    fn press_stops_a_fling() {
+        let mut state = kinetic();
+        let y = swipe(&mut state, 90.0, 5);
+
+        send(&mut state, finger(lifted, y), y);
+        redraw(&mut state);
+
+        send(&mut state, finger(pressed, 50.0), 50.0);
+        let pressed_at = offset(&state);
+
+        assert!(!state.is_flinging());
+        assert!(!redraw(&mut state).1);
+        assert_eq!(offset(&state), pressed_at);
+    }
+
+    #[test]
+    fn resting_finger_does_not_fling() {
+        let mut state = kinetic();
+        let y = swipe(&mut state, 90.0, 5);
+
+        wait(Duration::from_millis(500));
+
+        assert!(!send(&mut state, finger(lifted, y), y).1);
+        assert!(!state.is_flinging());
+    }
+
+    #[test]
+    fn no_fling_without_kinetic() {
+        let mut state = State::new();
+        let y = swipe(&mut state, 90.0, 5);
+
+        assert!(!send(&mut state, finger(lifted, y), y).1);
+        assert!(!redraw(&mut state).1);
+        assert_eq!(offset(&state), 50.0);
+    }
+}
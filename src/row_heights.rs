@@ -0,0 +1,293 @@
+//! Keep track of the height of every row of a virtualized list.
+
+/// The heights of the rows of a list, either measured or estimated.
+///
+/// Heights are kept in a Fenwick tree, so both the offset of a row and the
+/// row found at a given offset are computed in `O(log n)`.
+#[derive(Debug, Clone, Default)]
+pub struct RowHeights {
+    estimate: f32,
+    measured: Vec<Option<f32>>,
+    sums: Vec<f32>,
+}
+
+impl RowHeights {
+    /// Creates [`RowHeights`] for `len` rows of `estimate` pixels each.
+    pub fn new(len: usize, estimate: f32) -> Self {
+        let mut heights = Self {
+            estimate,
+            measured: vec![None; len],
+            sums: Vec::new(),
+        };
+
+        heights.rebuild();
+        heights
+    }
+
+    /// Returns the amount of rows.
+    pub fn len(&self) -> usize {
+        self.measured.len()
+    }
+
+    /// Changes the amount of rows and the estimate used for rows that were
+    /// never measured, keeping the measurements of the remaining rows.
+    pub fn resize(&mut self, len: usize, estimate: f32) {
+        if self.len() == len && self.estimate == estimate {
+            return;
+        }
+
+        self.measured.resize(len, None);
+        self.estimate = estimate;
+        self.rebuild();
+    }
+
+    /// Inserts `count` rows that were never measured before the row at
+    /// `index`.
+    pub fn insert(&mut self, index: usize, count: usize) {
+        let index = index.min(self.len());
+
+        self.measured
+            .splice(index..index, std::iter::repeat_n(None, count));
+        self.rebuild();
+    }
+
+    /// Removes `count` rows, starting at the row at `index`.
+    pub fn remove(&mut self, index: usize, count: usize) {
+        let start = index.min(self.len());
+        let end = index.saturating_add(count).min(self.len());
+
+        self.measured.drain(start..end);
+        self.rebuild();
+    }
+
+    /// Returns the height of the row at `index`.
+    pub fn height(&self, index: usize) -> f32 {
+        self.measured[index].unwrap_or(self.estimate)
+    }
+
+    /// Records the measured `height` of the row at `index` and returns how
+    /// much it differs from the previous height of the row.
+    pub fn set(&mut self, index: usize, height: f32) -> f32 {
+        let delta = height - self.height(index);
+        self.measured[index] = Some(height);
+
+        if delta != 0.0 {
+            let mut i = index + 1;
+
+            while i < self.sums.len() {
+                self.sums[i] += delta;
+                i += i & i.wrapping_neg();
+            }
+        }
+
+        delta
+    }
+
+    /// Returns the distance between the top of the list and the top of the
+    /// row at `index`.
+    pub fn offset_of(&self, index: usize) -> f32 {
+        let mut i = index.min(self.len());
+        let mut offset = 0.0;
+
+        while i > 0 {
+            offset += self.sums[i];
+            i -= i & i.wrapping_neg();
+        }
+
+        offset
+    }
+
+    /// Returns the total height of the list.
+    pub fn total(&self) -> f32 {
+        self.offset_of(self.len())
+    }
+
+    /// Returns the index of the row found at `offset` pixels from the top of
+    /// the list.
+    pub fn index_at(&self, offset: f32) -> usize {
+        let len = self.len();
+        let mut index = 0;
+        let mut remaining = offset;
+        let mut step = len.checked_next_power_of_two().unwrap_or(0);
+
+        while step > 0 {
+            let next = index + step;
+
+            if next <= len && self.sums[next] <= remaining {
+                index = next;
+                remaining -= self.sums[next];
+            }
+
+            step /= 2;
+        }
+
+        index.min(len.saturating_sub(1))
+    }
+
+    fn rebuild(&mut self) {
+        let len = self.len();
+        self.sums = vec![0.0; len + 1];
+
+        for index in 0..len {
+            let i = index + 1;
+            self.sums[i] += self.height(index);
+
+            let parent = i + (i & i.wrapping_neg());
+
+            if parent <= len {
+                self.sums[parent] += self.sums[i];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowHeights;
+
+    /// The heights of the rows, as summed up by hand.
+    fn naive(heights: &RowHeights) -> Vec<f32> {
+        (0..heights.len())
+            .map(|index| heights.height(index))
+            .collect()
+    }
+
+    fn naive_offset_of(rows: &[f32], index: usize) -> f32 {
+        rows.iter().take(index).sum()
+    }
+
+    fn naive_index_at(rows: &[f32], offset: f32) -> usize {
+        let mut index = 0;
+
+        while index + 1 < rows.len() && naive_offset_of(rows, index + 1) <= offset {
+            index += 1;
+        }
+
+        index
+    }
+
+    fn assert_matches_naive(heights: &RowHeights) {
+        let rows = naive(heights);
+        let total = naive_offset_of(&rows, rows.len());
+
+        for index in 0..=rows.len() + 1 {
+            assert_eq!(
+                heights.offset_of(index),
+                naive_offset_of(&rows, index),
+                "offset_of({index}) of {rows:?}"
+            );
+        }
+
+        assert_eq!(heights.total(), total, "total of {rows:?}");
+
+        let mut offset = -5.0;
+
+        while offset <= total + 20.0 {
+            assert_eq!(
+                heights.index_at(offset),
+                naive_index_at(&rows, offset),
+                "index_at({offset}) of {rows:?}"
+            );
+
+            offset += 2.5;
+        }
+    }
+
+    #[test]
+    fn matches_naive_prefix_sums() {
+        for len in [0, 1, 2, 3, 7, 8, 9, 16, 33] {
+            let mut heights = RowHeights::new(len, 10.0);
+            assert_matches_naive(&heights);
+
+            for index in (0..len).step_by(3) {
+                heights.set(index, (index % 4 + 1) as f32 * 5.0);
+            }
+
+            assert_matches_naive(&heights);
+        }
+    }
+
+    #[test]
+    fn set_returns_the_difference() {
+        let mut heights = RowHeights::new(4, 10.0);
+
+        assert_eq!(heights.set(2, 25.0), 15.0);
+        assert_eq!(heights.set(2, 20.0), -5.0);
+        assert_eq!(heights.set(2, 20.0), 0.0);
+        assert_eq!(heights.total(), 50.0);
+    }
+
+    #[test]
+    fn offsets_past_the_total() {
+        let heights = RowHeights::new(8, 10.0);
+
+        assert_eq!(heights.index_at(80.0), 7);
+        assert_eq!(heights.index_at(1000.0), 7);
+        assert_eq!(heights.offset_of(100), 80.0);
+        assert_eq!(RowHeights::new(0, 10.0).index_at(1000.0), 0);
+    }
+
+    #[test]
+    fn resize_keeps_measurements() {
+        let mut heights = RowHeights::new(4, 10.0);
+        heights.set(1, 30.0);
+
+        heights.resize(8, 20.0);
+        assert_eq!(
+            naive(&heights),
+            [20.0, 30.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0]
+        );
+        assert_matches_naive(&heights);
+
+        heights.resize(0, 20.0);
+        assert_matches_naive(&heights);
+    }
+
+    #[test]
+    fn set_after_insert_and_remove() {
+        let cases: &[(usize, usize, bool)] = &[
+            (0, 3, true),
+            (4, 4, true),
+            (8, 1, true),
+            (100, 2, true),
+            (0, 2, false),
+            (3, 3, false),
+            (6, 100, false),
+            (100, 1, false),
+        ];
+
+        for &(index, count, is_insert) in cases {
+            let mut heights = RowHeights::new(8, 10.0);
+
+            for row in 0..8 {
+                heights.set(row, (row + 1) as f32);
+            }
+
+            let mut expected = naive(&heights);
+
+            if is_insert {
+                heights.insert(index, count);
+
+                let index = index.min(expected.len());
+                expected.splice(index..index, std::iter::repeat_n(10.0, count));
+            } else {
+                heights.remove(index, count);
+
+                let start = index.min(expected.len());
+                let end = (index + count).min(expected.len());
+                expected.drain(start..end);
+            }
+
+            assert_eq!(naive(&heights), expected);
+            assert_matches_naive(&heights);
+
+            for row in (0..heights.len()).rev().step_by(2) {
+                heights.set(row, 3.0 * row as f32 + 1.0);
+                expected[row] = 3.0 * row as f32 + 1.0;
+            }
+
+            assert_eq!(naive(&heights), expected);
+            assert_matches_naive(&heights);
+        }
+    }
+}
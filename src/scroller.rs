@@ -1,23 +1,30 @@
 // use iced::widget::scrollable;
 use iced::{touch, Event};
 use iced_native::event;
+use iced_native::keyboard;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
 use iced_native::widget::horizontal_space;
 use iced_native::widget::tree::{self, Tree};
-use iced_native::{Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget};
+use iced_native::window;
+use iced_native::{Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector, Widget};
 
 use ouroboros::self_referencing;
 use std::cell::{RefCell, RefMut};
 use std::marker::PhantomData;
 use std::ops::Deref;
 
+use crate::kinetic::{Kinetic, Momentum};
+use crate::new_scrollable::Wheel;
+
 #[allow(missing_debug_implementations)]
 pub struct Scroller<'a, Message, Renderer> {
     size: Size,
-    scroll_by: f32,
+    content_height: Option<f32>,
+    wheel: Wheel,
+    kinetic: Option<Kinetic>,
     view: Box<dyn Fn(f32) -> Element<'a, Message, Renderer> + 'a>,
     content: RefCell<Content<'a, Message, Renderer>>,
 }
@@ -33,7 +40,9 @@ where
     ) -> Self {
         Self {
             size,
-            scroll_by,
+            content_height: None,
+            wheel: Wheel::new().line_height(scroll_by),
+            kinetic: None,
             view: Box::new(view),
             content: RefCell::new(Content {
                 offset: 0.0,
@@ -42,6 +51,83 @@ where
             }),
         }
     }
+
+    /// Makes the [`Scroller`] keep scrolling after it is flung with a finger,
+    /// slowing down as described by the given [`Kinetic`].
+    ///
+    /// The speed of a fling is measured in pixels of content, so the
+    /// [`Scroller::content_height`] should be set as well.
+    pub fn kinetic(mut self, kinetic: Kinetic) -> Self {
+        self.kinetic = Some(kinetic);
+        self
+    }
+
+    /// Sets the height of the content spanned by the slider of the
+    /// [`Scroller`].
+    ///
+    /// A finger then drags the content along, instead of the slider, and the
+    /// mouse wheel scrolls by pixels of content.
+    pub fn content_height(mut self, content_height: f32) -> Self {
+        self.content_height = Some(content_height);
+        self
+    }
+
+    /// Sets how the [`Scroller`] scrolls with the mouse wheel, replacing the
+    /// pixels per line it was created with.
+    ///
+    /// The [`Wheel`] scrolls by pixels of content once the
+    /// [`Scroller::content_height`] is set, and by pixels of the slider
+    /// otherwise.
+    pub fn wheel(mut self, wheel: Wheel) -> Self {
+        self.wheel = wheel;
+        self
+    }
+
+    /// Converts `delta` pixels of content into how far the slider moves.
+    fn slider_delta(&self, delta: f32) -> f32 {
+        match self.content_height {
+            Some(content_height) if content_height > 0.0 => {
+                delta * self.size.height / content_height
+            }
+            _ => delta,
+        }
+    }
+
+    /// Returns how far a `delta` of the mouse wheel moves the slider from
+    /// `slider_position`.
+    fn wheel_delta(&self, slider_position: f32, delta: mouse::ScrollDelta) -> f32 {
+        // The scroller only moves vertically.
+        let y = self.wheel.delta(delta, keyboard::Modifiers::default()).y;
+
+        match delta {
+            mouse::ScrollDelta::Lines { .. } => {
+                // Rows are snapped to in pixels of content.
+                let from = self.content_offset(slider_position);
+                let to = self
+                    .wheel
+                    .snap(
+                        Vector::new(0.0, from - y),
+                        Vector::new(0.0, y),
+                        Vector::new(0.0, 0.0),
+                    )
+                    .y;
+
+                self.slider_delta(from - to)
+            }
+            mouse::ScrollDelta::Pixels { .. } => self.slider_delta(y),
+        }
+    }
+
+    /// Converts the position of the slider into an offset in pixels of
+    /// content.
+    fn content_offset(&self, slider_position: f32) -> f32 {
+        match self.content_height {
+            Some(content_height) if self.size.height > 0.0 => {
+                slider_position * content_height / self.size.height
+            }
+            _ => slider_position,
+        }
+    }
 }
 
 struct Content<'a, Message, Renderer> {
@@ -102,12 +188,16 @@ struct State {
 pub struct ScrollerState {
     offset_pixels: f32,
     height: f32,
+    touched_at: Option<Point>,
+    momentum: Momentum,
 }
 impl ScrollerState {
     pub fn new(height: f32) -> Self {
         Self {
             offset_pixels: 0.0,
             height,
+            touched_at: None,
+            momentum: Momentum::default(),
         }
     }
     pub fn scroll(&mut self, delta_y: f32) {
@@ -159,19 +249,41 @@ where
         let state = tree.state.downcast_mut::<State>();
         let mut content = self.content.borrow_mut();
 
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // println!("lines: {}", y);
-                            state.scroller.scroll(y * self.scroll_by);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            // println!("pixels: {}", y);
-                            state.scroller.scroll(y);
-                        }
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.scroller.momentum.stop();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(kinetic) = &self.kinetic {
+                    let scroller = &mut state.scroller;
+                    let mut momentum = scroller.momentum;
+
+                    momentum.fling(now, kinetic, |delta| {
+                        let offset = scroller.offset_pixels;
+
+                        scroller.scroll(self.slider_delta(delta.y));
+                        scroller.offset_pixels != offset
+                    });
+
+                    scroller.momentum = momentum;
+
+                    if scroller.momentum.is_flinging() {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
                     }
+                }
+            }
+            _ => {}
+        }
+
+        // A finger keeps dragging past the edges of the scroller, so a swipe
+        // lifted outside still ends the touch and flings it.
+        if is_mouse_over || state.scroller.touched_at.is_some() {
+            match event {
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) if is_mouse_over => {
+                    let delta = self.wheel_delta(state.scroller.offset_pixels, delta);
+
+                    state.scroller.scroll(delta);
 
                     // notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
 
@@ -180,21 +292,39 @@ where
                 Event::Touch(event) => {
                     match event {
                         touch::Event::FingerPressed { .. } => {
-                            // state.scroll_box_touched_at = Some(cursor_position);
+                            state.scroller.touched_at = Some(cursor_position);
+
+                            if let Some(kinetic) = &self.kinetic {
+                                state.scroller.momentum.press(kinetic.now());
+                            }
                         }
                         touch::Event::FingerMoved { .. } => {
-                            // if let Some(scroll_box_touched_at) = state.scroll_box_touched_at {
-                            //     let delta = cursor_position.y - scroll_box_touched_at.y;
-
-                            //     state.scroll(delta, bounds, content_bounds);
-
-                            //     state.scroll_box_touched_at = Some(cursor_position);
-
-                            //     // notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
-                            // }
+                            if let Some(touched_at) = state.scroller.touched_at {
+                                let delta = cursor_position.y - touched_at.y;
+
+                                state.scroller.scroll(self.slider_delta(delta));
+                                state.scroller.touched_at = Some(cursor_position);
+
+                                if let Some(kinetic) = &self.kinetic {
+                                    state
+                                        .scroller
+                                        .momentum
+                                        .track(Vector::new(0.0, delta), kinetic.now());
+                                }
+                            }
                         }
-                        touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. } => {
-                            // state.scroll_box_touched_at = None;
+                        touch::Event::FingerLifted { .. } => {
+                            state.scroller.touched_at = None;
+
+                            if let Some(kinetic) = &self.kinetic {
+                                if state.scroller.momentum.release(kinetic.now(), kinetic) {
+                                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                                }
+                            }
+                        }
+                        touch::Event::FingerLost { .. } => {
+                            state.scroller.touched_at = None;
+                            state.scroller.momentum.stop();
                         }
                     }
 
@@ -437,3 +567,62 @@ where
         .unwrap_or(iced_native::event::Status::Ignored)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Scroller;
+
+    use iced_native::mouse::ScrollDelta;
+    use iced_native::widget::Space;
+    use iced_native::{Length, Size};
+
+    use crate::new_scrollable::Wheel;
+
+    fn scroller() -> Scroller<'static, (), iced::Renderer> {
+        Scroller::new(Size::new(100.0, 500.0), 10.0, |_| {
+            Space::with_width(Length::Fill).into()
+        })
+    }
+
+    #[test]
+    fn finger_moves_the_slider_by_its_share_of_the_content() {
+        // 100k rows of 20 pixels behind a slider of 500 pixels.
+        let scroller = scroller().content_height(2_000_000.0);
+
+        assert_eq!(scroller.slider_delta(10.0), 0.0025);
+        assert_eq!(scroller.slider_delta(-4000.0), -1.0);
+    }
+
+    #[test]
+    fn wheel_scrolls_by_rows_of_content() {
+        // 100k rows of 20 pixels behind a slider of 500 pixels.
+        let scroller = scroller()
+            .content_height(2_000_000.0)
+            .wheel(Wheel::new().rows(1, 20.0));
+        let line = ScrollDelta::Lines { x: 0.0, y: -1.0 };
+
+        assert_eq!(scroller.wheel_delta(0.0, line), -0.005);
+
+        // A slider between rows snaps to the closest one.
+        let slider_position = 0.0051;
+        let delta = scroller.wheel_delta(slider_position, line);
+        let row = (slider_position - delta) * 2_000_000.0 / 500.0 / 20.0;
+
+        assert!((row - 2.0).abs() < 1e-3, "row {row}");
+    }
+
+    #[test]
+    fn wheel_scales_pixels_of_content() {
+        let scroller = scroller()
+            .content_height(2_000_000.0)
+            .wheel(Wheel::new().pixel_multiplier(2.0));
+        let pixels = ScrollDelta::Pixels { x: 0.0, y: -100.0 };
+
+        assert_eq!(scroller.wheel_delta(0.0, pixels), -0.05);
+    }
+
+    #[test]
+    fn finger_moves_the_slider_without_content_height() {
+        assert_eq!(scroller().slider_delta(10.0), 10.0);
+    }
+}
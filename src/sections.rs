@@ -0,0 +1,96 @@
+//! Group the rows of a list into sections.
+
+/// The sections of a grouped list.
+///
+/// Every section is laid out as its header followed by its items, so the rows
+/// of the list are indexed by flat positions that cover both. [`Sections`]
+/// maps those positions to and from `(section, item)` pairs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sections {
+    headers: Vec<usize>,
+    len: usize,
+}
+
+/// What is found at a flat position of a sectioned list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The header of the given section.
+    Header(usize),
+    /// An item of a section.
+    Item {
+        /// The index of the section.
+        section: usize,
+        /// The index of the item within its section.
+        item: usize,
+    },
+}
+
+impl Sections {
+    /// Creates [`Sections`] holding the given amount of items each.
+    pub fn new(item_counts: impl IntoIterator<Item = usize>) -> Self {
+        let mut headers = Vec::new();
+        let mut len = 0;
+
+        for count in item_counts {
+            headers.push(len);
+            len += count + 1;
+        }
+
+        Self { headers, len }
+    }
+
+    /// Returns the amount of flat positions, headers included.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no sections.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Returns the amount of sections.
+    pub fn section_count(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Returns the amount of items in `section`.
+    pub fn item_count(&self, section: usize) -> usize {
+        self.end_of(section) - self.headers[section] - 1
+    }
+
+    /// Returns the flat position of the header of `section`.
+    pub fn header_index(&self, section: usize) -> usize {
+        self.headers[section]
+    }
+
+    /// Returns the flat position of the given `item` of `section`, if any.
+    pub fn flat_index(&self, section: usize, item: usize) -> Option<usize> {
+        (section < self.section_count() && item < self.item_count(section))
+            .then(|| self.headers[section] + 1 + item)
+    }
+
+    /// Returns the section that the flat position `index` belongs to.
+    pub fn section_of(&self, index: usize) -> Option<usize> {
+        (index < self.len).then(|| self.headers.partition_point(|&header| header <= index) - 1)
+    }
+
+    /// Returns what is found at the flat position `index`, if any.
+    pub fn position(&self, index: usize) -> Option<Position> {
+        let section = self.section_of(index)?;
+        let header = self.headers[section];
+
+        Some(if index == header {
+            Position::Header(section)
+        } else {
+            Position::Item {
+                section,
+                item: index - header - 1,
+            }
+        })
+    }
+
+    fn end_of(&self, section: usize) -> usize {
+        self.headers.get(section + 1).copied().unwrap_or(self.len)
+    }
+}
@@ -0,0 +1,99 @@
+//! Select the rows of a list.
+use iced_native::{Background, Color};
+use iced_style::Theme;
+
+/// How many rows of a list can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Rows cannot be selected.
+    #[default]
+    None,
+    /// A single row can be selected.
+    ///
+    /// Clicking a row selects it, and Ctrl-clicking it clears the selection.
+    Single,
+    /// Any amount of rows can be selected.
+    ///
+    /// Clicking a row selects it alone, Ctrl-clicking it toggles it,
+    /// Shift-clicking it selects the range from the last clicked row, and
+    /// Ctrl+A selects every row.
+    Multiple,
+}
+
+/// The appearance of a selected row.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] drawn behind the row.
+    pub background: Background,
+    /// The border radius of the highlight.
+    pub border_radius: f32,
+    /// The border width of the highlight.
+    pub border_width: f32,
+    /// The border [`Color`] of the highlight.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a selected row.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a selected row.
+    fn selected(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the line showing where a dragged row would
+    /// be dropped.
+    fn drop_indicator(&self, style: &Self::Style) -> Color;
+}
+
+/// The style of selected rows for the built-in [`Theme`].
+#[derive(Default)]
+#[allow(missing_debug_implementations)]
+pub enum Style {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl From<fn(&Theme) -> Appearance> for Style {
+    fn from(f: fn(&Theme) -> Appearance) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = Style;
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Style::Default => Appearance {
+                background: self.extended_palette().primary.weak.color.into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Style::Custom(custom) => custom.selected(self),
+        }
+    }
+
+    fn drop_indicator(&self, style: &Self::Style) -> Color {
+        match style {
+            Style::Default => self.extended_palette().primary.strong.color,
+            Style::Custom(custom) => custom.drop_indicator(self),
+        }
+    }
+}
+
+impl StyleSheet for fn(&Theme) -> Appearance {
+    type Style = Theme;
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        (self)(style)
+    }
+
+    fn drop_indicator(&self, style: &Self::Style) -> Color {
+        style.drop_indicator(&Style::Default)
+    }
+}
@@ -0,0 +1,197 @@
+//! Stand in for content that is not available yet.
+use iced_native::layout;
+use iced_native::renderer;
+use iced_native::widget::Tree;
+use iced_native::{Background, Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
+use iced_style::Theme;
+
+/// A bar standing in for a row that is not available yet.
+///
+/// It fills its row, minus some padding, with a background taken from the
+/// theme.
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    width: Length,
+    height: Length,
+    padding: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of a [`Skeleton`].
+    pub const DEFAULT_PADDING: f32 = 4.0;
+
+    /// Creates a new [`Skeleton`] filling the available space.
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Skeleton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Skeleton`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the space between the bounds of the [`Skeleton`] and its bar.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`].
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Renderer> Default for Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Skeleton<Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let bar = Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.y + self.padding,
+            width: (bounds.width - 2.0 * self.padding).max(0.0),
+            height: (bounds.height - 2.0 * self.padding).max(0.0),
+        };
+
+        if bar.width == 0.0 || bar.height == 0.0 {
+            return;
+        }
+
+        let appearance = theme.appearance(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                border_radius: appearance.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Skeleton<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(skeleton: Skeleton<Renderer>) -> Self {
+        Self::new(skeleton)
+    }
+}
+
+/// The appearance of a [`Skeleton`].
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the bar.
+    pub background: Background,
+    /// The border radius of the bar.
+    pub border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a [`Skeleton`].
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a [`Skeleton`].
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The style of a [`Skeleton`] for the built-in [`Theme`].
+#[derive(Default)]
+#[allow(missing_debug_implementations)]
+pub enum Style {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl From<fn(&Theme) -> Appearance> for Style {
+    fn from(f: fn(&Theme) -> Appearance) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Style::Default => Appearance {
+                background: self.extended_palette().background.weak.color.into(),
+                border_radius: 4.0,
+            },
+            Style::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl StyleSheet for fn(&Theme) -> Appearance {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        (self)(style)
+    }
+}
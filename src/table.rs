@@ -0,0 +1,533 @@
+//! Display large tables by only building the cells inside the viewport.
+use std::cell::{RefCell, RefMut};
+use std::ops::Range;
+
+use iced_native::event::{self, Event};
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::widget::tree::{self, Tree};
+use iced_native::widget::Operation;
+use iced_native::{Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector, Widget};
+
+use crate::flatlist::{get_visible_range, Overscan};
+use crate::new_scrollable::{self, Id, Properties, StyleSheet, Viewport};
+
+/// A table that virtualizes both its rows and its columns.
+///
+/// Only the cells intersecting the viewport are built, so the cost of a
+/// frame depends on the size of the viewport rather than on the size of the
+/// table.
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    width: Length,
+    height: Length,
+    row_count: usize,
+    row_height: f32,
+    column_offsets: Vec<f32>,
+    overscan: Overscan,
+    vertical: Properties,
+    horizontal: Properties,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    cell: Box<dyn Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a>,
+    content: RefCell<Content<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Table`] with `row_count` rows of `row_height` pixels
+    /// and one column per width in `column_widths`.
+    ///
+    /// The cells are built on demand by `cell`, given their row and column.
+    pub fn new(
+        row_count: usize,
+        row_height: f32,
+        column_widths: impl IntoIterator<Item = f32>,
+        cell: impl Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        let column_offsets = std::iter::once(0.0)
+            .chain(column_widths.into_iter().scan(0.0, |offset, width| {
+                *offset += width.max(0.0);
+                Some(*offset)
+            }))
+            .collect();
+
+        Self {
+            id: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_count,
+            row_height: row_height.max(1.0),
+            column_offsets,
+            overscan: Overscan::new(1),
+            vertical: Properties::default(),
+            horizontal: Properties::default(),
+            style: Default::default(),
+            cell: Box::new(cell),
+            content: RefCell::new(Content {
+                window: None,
+                layout: layout::Node::new(Size::ZERO),
+                cells: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Table`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Overscan`] of the [`Table`], applied to both its rows and
+    /// its columns.
+    pub fn overscan(mut self, overscan: Overscan) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Configures the vertical scrollbar of the [`Table`].
+    pub fn vertical_scroll(mut self, properties: Properties) -> Self {
+        self.vertical = properties;
+        self
+    }
+
+    /// Configures the horizontal scrollbar of the [`Table`].
+    pub fn horizontal_scroll(mut self, properties: Properties) -> Self {
+        self.horizontal = properties;
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn column_count(&self) -> usize {
+        self.column_offsets.len() - 1
+    }
+
+    fn content_size(&self) -> Size {
+        Size::new(
+            self.column_offsets[self.column_count()],
+            self.row_count as f32 * self.row_height,
+        )
+    }
+
+    fn content_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let size = self.content_size();
+
+        Rectangle {
+            width: size.width,
+            height: size.height,
+            ..bounds
+        }
+    }
+
+    /// Returns the range of columns intersecting a viewport `width` pixels
+    /// wide scrolled by `offset`, extended by the [`Overscan`].
+    fn visible_columns(&self, offset: f32, width: f32) -> Range<usize> {
+        let count = self.column_count();
+        let start = self
+            .column_offsets
+            .partition_point(|&left| left <= offset)
+            .saturating_sub(1)
+            .min(count.saturating_sub(1));
+        let end = self
+            .column_offsets
+            .partition_point(|&left| left < offset + width)
+            .min(count)
+            .max(start);
+        let (start, end) = self.overscan.apply(start, end, count);
+
+        start..end
+    }
+
+    /// Brings the realized cells up to date with the current scroll offset.
+    fn content(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) -> RefMut<'_, Content<'a, Message, Renderer>> {
+        let offset = state
+            .scrollable
+            .borrow()
+            .offset(bounds, self.content_bounds(bounds));
+        let mut content = self.content.borrow_mut();
+
+        content.update(
+            &mut state.tree.borrow_mut(),
+            renderer,
+            bounds.size(),
+            offset,
+            self,
+        );
+
+        content
+    }
+}
+
+struct Content<'a, Message, Renderer> {
+    window: Option<Window>,
+    layout: layout::Node,
+    cells: Vec<Element<'a, Message, Renderer>>,
+}
+
+/// The cells realized by a [`Table`] and the viewport they were laid out in.
+#[derive(Debug, Clone, PartialEq)]
+struct Window {
+    rows: Range<usize>,
+    columns: Range<usize>,
+    offset: Vector,
+    size: Size,
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        size: Size,
+        offset: Vector,
+        table: &Table<'a, Message, Renderer>,
+    ) {
+        let (start, end) = get_visible_range(
+            table.row_count,
+            offset.y,
+            table.row_height,
+            size.height,
+            table.overscan,
+        );
+        let window = Window {
+            rows: start..end,
+            columns: table.visible_columns(offset.x, size.width),
+            offset,
+            size,
+        };
+
+        let is_synced = tree.children.len() == self.cells.len();
+
+        if is_synced && self.window.as_ref() == Some(&window) {
+            return;
+        }
+
+        let is_realized = self.window.as_ref().is_some_and(|realized| {
+            realized.rows == window.rows && realized.columns == window.columns
+        });
+
+        if !is_realized || !is_synced {
+            self.cells = window
+                .rows
+                .clone()
+                .flat_map(|row| window.columns.clone().map(move |column| (row, column)))
+                .map(|(row, column)| (table.cell)(row, column))
+                .collect();
+
+            tree.diff_children(&self.cells);
+        }
+
+        let positions = window
+            .rows
+            .clone()
+            .flat_map(|row| window.columns.clone().map(move |column| (row, column)));
+
+        let cells = self
+            .cells
+            .iter()
+            .zip(positions)
+            .map(|(cell, (row, column))| {
+                let left = table.column_offsets[column];
+                let size = Size::new(table.column_offsets[column + 1] - left, table.row_height);
+                let mut node = cell
+                    .as_widget()
+                    .layout(renderer, &layout::Limits::new(size, size));
+
+                node.move_to(Point::new(
+                    left - offset.x,
+                    row as f32 * table.row_height - offset.y,
+                ));
+                node
+            })
+            .collect();
+
+        self.layout = layout::Node::with_children(
+            size,
+            vec![layout::Node::with_children(table.content_size(), cells)],
+        );
+        self.window = Some(window);
+    }
+}
+
+struct State {
+    tree: RefCell<Tree>,
+    scrollable: RefCell<new_scrollable::State>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Table<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            tree: RefCell::new(Tree::empty()),
+            scrollable: RefCell::new(new_scrollable::State::new()),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(self.content_size()))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.scrollable(state.scrollable.get_mut(), self.id.as_ref().map(|id| &id.0));
+        operation.custom(
+            &mut Viewport::new(
+                state.scrollable.get_mut(),
+                layout.bounds(),
+                self.content_bounds(layout.bounds()),
+            ),
+            self.id.as_ref().map(|id| &id.0),
+        );
+        operation.custom(state.scrollable.get_mut(), self.id.as_ref().map(|id| &id.0));
+
+        let mut content = self.content(state, renderer, layout.bounds());
+        let Content {
+            layout: node,
+            cells,
+            ..
+        } = &mut *content;
+        let tree = state.tree.get_mut();
+        let layout = Layout::with_offset(layout.position() - Point::ORIGIN, node);
+
+        operation.container(self.id.as_ref().map(|id| &id.0), &mut |operation| {
+            let content_layout = layout.children().next().unwrap();
+
+            cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(content_layout.children())
+                .for_each(|((cell, tree), layout)| {
+                    cell.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let mut content = self.content(state, renderer, layout.bounds());
+        let Content {
+            layout: node,
+            cells,
+            ..
+        } = &mut *content;
+        let State { tree, scrollable } = state;
+        let tree = tree.get_mut();
+
+        new_scrollable::update(
+            scrollable.get_mut(),
+            event,
+            Layout::with_offset(layout.position() - Point::ORIGIN, node),
+            cursor_position,
+            clipboard,
+            shell,
+            &self.vertical,
+            Some(&self.horizontal),
+            &None,
+            |event, layout, cursor_position, clipboard, shell| {
+                cells
+                    .iter_mut()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                    .map(|((cell, tree), layout)| {
+                        cell.as_widget_mut().on_event(
+                            tree,
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let content = self.content(state, renderer, layout.bounds());
+        let tree = state.tree.borrow();
+
+        new_scrollable::draw(
+            &state.scrollable.borrow(),
+            renderer,
+            theme,
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            &self.vertical,
+            Some(&self.horizontal),
+            &self.style,
+            |renderer, layout, cursor_position, viewport| {
+                content
+                    .cells
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .for_each(|((cell, tree), layout)| {
+                        cell.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        )
+                    });
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let content = self.content(state, renderer, layout.bounds());
+        let tree = state.tree.borrow();
+
+        new_scrollable::mouse_interaction(
+            &state.scrollable.borrow(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout),
+            cursor_position,
+            &self.vertical,
+            Some(&self.horizontal),
+            |layout, cursor_position, viewport| {
+                content
+                    .cells
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((cell, tree), layout)| {
+                        cell.as_widget().mouse_interaction(
+                            tree,
+                            layout,
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        drop(self.content(state, renderer, layout.bounds()));
+
+        let Content {
+            layout: node,
+            cells,
+            ..
+        } = self.content.get_mut();
+        let layout = Layout::with_offset(layout.position() - Point::ORIGIN, node);
+
+        overlay::from_children(
+            cells,
+            state.tree.get_mut(),
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Self::new(table)
+    }
+}